@@ -0,0 +1,717 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+use syn::{Expr, Ident, Lit, Path, RangeLimits, Type, UnOp};
+
+/// How the values of an argument are mapped to indices of the look-up table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ArgKind {
+    /// Integer primitives, indexed by `value - min`.
+    Int,
+    /// `char`, indexed by `value as u32 - min as u32`.
+    Char,
+    /// A type implementing `PrecalcEnum`, given a range of its values such as
+    /// `Ordering::Less..=Ordering::Greater`. Indexed by
+    /// `value as isize - min as isize` and rebuilt from its `VARIANTS`.
+    Enum,
+    /// A zero-sized argument fixed to a single value with `m = marker(EXPR)`.
+    /// Its dimension has a single entry, at index 0.
+    Marker,
+    /// A fieldless enum given a slice of its values, e.g. `k = Kind::VARIANTS`.
+    /// Indexed by the position of the value in the slice, found through a
+    /// table of positions by discriminant.
+    Variants,
+}
+
+const INTEGERS: [&str; 12] = [
+    "i8", "u8", "i16", "u16", "i32", "u32", "i64", "u64", "i128", "u128", "isize", "usize",
+];
+
+/// The name of an integer primitive type, e.g. `u8` for `core::primitive::u8`.
+fn integer_name(ty: &Type) -> Option<String> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    let name = segment.ident.to_string();
+    (path.qself.is_none() && segment.arguments.is_none() && INTEGERS.contains(&name.as_str()))
+        .then_some(name)
+}
+
+impl ArgKind {
+    /// The kind of an argument of type `ty` precalculated over `range`.
+    /// Types spelled as an integer primitive or `char` are known by name.
+    /// Any other type, such as an alias, is an integer unless the bounds of
+    /// its range are paths or `bool` literals, as in
+    /// `Ordering::Less..=Ordering::Greater`, or it is a `VARIANTS` slice.
+    fn new(ty: &Type, range: &TokenStream) -> Self {
+        if integer_name(ty).is_some() {
+            return ArgKind::Int;
+        }
+        if matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("char")) {
+            return ArgKind::Char;
+        }
+        match syn::parse2::<Expr>(range.clone()) {
+            Ok(Expr::Range(range)) => {
+                let bounds = range
+                    .start
+                    .iter()
+                    .chain(&range.end)
+                    .map(|bound| &**bound)
+                    .collect::<Vec<_>>();
+                let is_char = |bound: &&Expr| matches!(bound, Expr::Lit(lit) if matches!(lit.lit, Lit::Char(_)));
+                let is_enum_value = |bound: &&Expr| match bound {
+                    Expr::Path(_) => true,
+                    Expr::Lit(lit) => matches!(lit.lit, Lit::Bool(_)),
+                    _ => false,
+                };
+                if bounds.iter().any(is_char) {
+                    ArgKind::Char
+                } else if !bounds.is_empty() && bounds.iter().all(is_enum_value) {
+                    ArgKind::Enum
+                } else {
+                    ArgKind::Int
+                }
+            }
+            Ok(Expr::Path(path)) if path.path.segments.last().unwrap().ident == "VARIANTS" => {
+                ArgKind::Enum
+            }
+            _ => ArgKind::Int,
+        }
+    }
+}
+
+/// User provided mapping between the values of an argument and dense table
+/// indices, set with `transform(arg = (to_index, from_index))`.
+pub(crate) struct Transform {
+    /// `const fn(T) -> usize`, the index of a value.
+    pub to_index: Path,
+    /// `const fn(usize) -> T`, the value stored at an index.
+    pub from_index: Path,
+}
+
+/// A function argument together with the range it is precalculated over.
+pub(crate) struct Arg {
+    pub ident: Ident,
+    pub ty: Box<Type>,
+    pub range: TokenStream,
+    pub kind: ArgKind,
+    /// With a transform, `range` is a range of indices instead of values.
+    pub transform: Option<Transform>,
+    /// Stride between the precalculated values, set with `x = 0..=100 by 10`.
+    pub step: Option<TokenStream>,
+    /// Whether out of range values are clamped into the range, set with
+    /// `x = clamp(0..=10)`.
+    pub clamp: bool,
+    /// Whether the range has no end, e.g. `a = 0..`, in which case it ends
+    /// at the largest value keeping the table within `max_bytes`.
+    pub open: bool,
+}
+
+impl Arg {
+    pub fn new(
+        ident: Ident,
+        ty: Box<Type>,
+        range: TokenStream,
+        transform: Option<Transform>,
+        step: Option<TokenStream>,
+    ) -> Self {
+        let kind = ArgKind::new(&ty, &range);
+        Arg {
+            ident,
+            ty,
+            range,
+            kind,
+            transform,
+            step,
+            clamp: false,
+            open: false,
+        }
+    }
+
+    /// Rewrites an exclusive integer range such as `-20..-10` to the
+    /// equivalent inclusive one, `-20..=-11`. A range without an end such as
+    /// `0..` is marked as open.
+    pub fn make_inclusive(&mut self) -> syn::Result<()> {
+        let Ok(Expr::Range(range)) = syn::parse2::<Expr>(self.range.clone()) else {
+            return Ok(());
+        };
+        if matches!(range.limits, RangeLimits::Closed(_)) {
+            return Ok(());
+        }
+        if self.kind != ArgKind::Int || self.transform.is_some() || self.step.is_some() {
+            return Err(syn::Error::new_spanned(
+                range,
+                "exclusive ranges are only supported for integer arguments without a step or transform, use `..=`",
+            ));
+        }
+        let (Some(start), Some(end)) = (&range.start, &range.end) else {
+            if range.start.is_some() {
+                self.open = true;
+                return Ok(());
+            }
+            return Err(syn::Error::new_spanned(
+                range,
+                "the range must have a start",
+            ));
+        };
+        let end = match literal(end) {
+            Some(end) => {
+                let end = end - 1;
+                let literal = proc_macro2::Literal::i128_unsuffixed(end.abs());
+                let sign = (end < 0).then(|| quote! { - });
+                quote! { #sign #literal }
+            }
+            None => quote! { (#end) - 1 },
+        };
+        self.range = quote! { #start..=#end };
+        Ok(())
+    }
+
+    /// Type of the range bounds: the argument type, or `usize` for indices
+    /// of a transformed argument.
+    pub fn range_ty(&self) -> TokenStream {
+        match &self.transform {
+            Some(_) => quote! { usize },
+            None => self.ty.to_token_stream(),
+        }
+    }
+
+    fn const_ident(&self, suffix: &str) -> Ident {
+        format_ident!("{}_{}", self.ident.to_string().to_uppercase(), suffix)
+    }
+
+    pub fn range_ident(&self) -> Ident {
+        self.const_ident("RANGE")
+    }
+
+    pub fn min_ident(&self) -> Ident {
+        self.const_ident("MIN")
+    }
+
+    pub fn max_ident(&self) -> Ident {
+        self.const_ident("MAX")
+    }
+
+    pub fn size_ident(&self) -> Ident {
+        self.const_ident("SIZE")
+    }
+
+    pub fn step_ident(&self) -> Ident {
+        self.const_ident("STEP")
+    }
+
+    /// First value of a stepped range, its largest one if descending.
+    fn start_ident(&self) -> Ident {
+        self.const_ident("START")
+    }
+
+    /// Number of values in the range, before checking the size of the table.
+    pub fn len_ident(&self) -> Ident {
+        self.const_ident("LEN")
+    }
+
+    /// Name of the variable holding this argument's index in the table.
+    pub fn index_var(&self) -> Ident {
+        format_ident!("{}_idx", self.ident)
+    }
+
+    /// Type of the user's range: `RangeFrom` for an open range,
+    /// `RangeInclusive` otherwise. A marker is given its single value.
+    pub fn user_range_ty(&self) -> TokenStream {
+        let ty = self.range_ty();
+        match self.kind {
+            ArgKind::Marker => return ty,
+            ArgKind::Variants => return quote! { &'static [#ty] },
+            _ => (),
+        }
+        match self.open {
+            true => quote! { std::ops::RangeFrom<#ty> },
+            false => quote! { std::ops::RangeInclusive<#ty> },
+        }
+    }
+
+    /// Constants describing the range of this argument, computed from the
+    /// user's range and step expressions stored in `user_range` and `user_step`.
+    /// An open range holds as many values as `open_entries`, the number of
+    /// entries of this dimension that fit in `max_bytes`.
+    pub fn const_defs(
+        &self,
+        user_range: &Ident,
+        user_step: &Ident,
+        open_entries: TokenStream,
+    ) -> TokenStream {
+        let ty = self.range_ty();
+        let range_ident = self.range_ident();
+        let min_ident = self.min_ident();
+        let max_ident = self.max_ident();
+        let size_ident = self.size_ident();
+        let len_ident = self.len_ident();
+
+        if self.kind == ArgKind::Marker {
+            return quote! {
+                const _: () = assert!(
+                    core::mem::size_of::<#ty>() == 0,
+                    "marker arguments must be zero-sized"
+                );
+                const #min_ident: #ty = #user_range;
+                const #max_ident: #ty = #user_range;
+                const #len_ident: usize = 1;
+                const #size_ident: usize = table_dimension(#len_ident);
+            };
+        }
+        if self.kind == ArgKind::Variants {
+            let values_ident = self.const_ident("VALUES");
+            let disc_min_ident = self.const_ident("DISC_MIN");
+            let disc_max_ident = self.const_ident("DISC_MAX");
+            let positions_ident = self.const_ident("POSITIONS");
+            return quote! {
+                const #values_ident: &[#ty] = #user_range;
+                const #len_ident: usize = {
+                    assert!(!#values_ident.is_empty(), "precalculated range is empty");
+                    #values_ident.len()
+                };
+                const #min_ident: #ty = #values_ident[0];
+                const #max_ident: #ty = #values_ident[#len_ident - 1];
+                const #disc_min_ident: isize = {
+                    let mut min = #values_ident[0] as isize;
+                    let mut i = 1;
+                    while i < #len_ident {
+                        if (#values_ident[i] as isize) < min {
+                            min = #values_ident[i] as isize;
+                        }
+                        i += 1;
+                    }
+                    min
+                };
+                const #disc_max_ident: isize = {
+                    let mut max = #values_ident[0] as isize;
+                    let mut i = 1;
+                    while i < #len_ident {
+                        if (#values_ident[i] as isize) > max {
+                            max = #values_ident[i] as isize;
+                        }
+                        i += 1;
+                    }
+                    max
+                };
+                // Position of each discriminant in the slice, `usize::MAX`
+                // for discriminants it doesn't list.
+                const #positions_ident: [usize; (#disc_max_ident - #disc_min_ident) as usize + 1] = {
+                    let mut positions = [usize::MAX; (#disc_max_ident - #disc_min_ident) as usize + 1];
+                    let mut i = 0;
+                    while i < #len_ident {
+                        let disc = (#values_ident[i] as isize - #disc_min_ident) as usize;
+                        assert!(
+                            positions[disc] == usize::MAX,
+                            "the values of a precalculated argument must be distinct"
+                        );
+                        positions[disc] = i;
+                        i += 1;
+                    }
+                    positions
+                };
+                const #size_ident: usize = table_dimension(#len_ident);
+            };
+        }
+
+        let kind_defs = match self.kind {
+            _ if self.transform.is_some() => None,
+            ArgKind::Int | ArgKind::Char | ArgKind::Marker | ArgKind::Variants => None,
+            ArgKind::Enum => {
+                let offset_ident = self.const_ident("OFFSET");
+                Some(quote! {
+                    const #offset_ident: usize = (#min_ident as isize
+                        - <#ty as recuerdame::PrecalcEnum>::VARIANTS[0] as isize)
+                        as usize;
+                    const _: () = {
+                        let variants = <#ty as recuerdame::PrecalcEnum>::VARIANTS;
+                        let mut i = 0;
+                        while i < variants.len() {
+                            assert!(
+                                variants[i] as isize == variants[0] as isize + i as isize,
+                                "PrecalcEnum::VARIANTS must list contiguous discriminants in ascending order"
+                            );
+                            i += 1;
+                        }
+                    };
+                })
+            }
+        };
+
+        let range = match self.open {
+            true => quote! {{
+                let start = #user_range.start;
+                let entries = #open_entries;
+                assert!(entries > 0, "max_bytes leaves no room for the open range");
+                let room = #ty::MAX as i128 - start as i128;
+                let span = if (entries as i128) - 1 < room { entries as i128 - 1 } else { room };
+                start..=(start as i128 + span) as #ty
+            }},
+            false => quote! { #user_range },
+        };
+        let step_ident = self.step_ident();
+        let start_ident = self.start_ident();
+        // A stepped range goes from its start to its end, which is descending
+        // with a negative step. MIN and MAX are always its smallest and
+        // largest values.
+        let (bound_defs, step) = match &self.step {
+            Some(_) => (
+                quote! {
+                    const #step_ident: i128 = {
+                        assert!(#user_step != 0, "the step of a precalculated range can't be 0");
+                        #user_step
+                    };
+                    const #start_ident: #ty = *#range_ident.start();
+                    const #min_ident: #ty = if #step_ident > 0 {
+                        *#range_ident.start()
+                    } else {
+                        *#range_ident.end()
+                    };
+                    const #max_ident: #ty = if #step_ident > 0 {
+                        *#range_ident.end()
+                    } else {
+                        *#range_ident.start()
+                    };
+                    const _: () = {
+                        let span = *#range_ident.end() as i128 - *#range_ident.start() as i128;
+                        assert!(
+                            span == 0 || (span > 0) == (#step_ident > 0),
+                            "a descending range needs a negative step, an ascending one a positive step"
+                        );
+                        assert!(
+                            span % #step_ident == 0,
+                            "the end of a stepped range must be a multiple of the step away from its start"
+                        );
+                    };
+                },
+                quote! { #step_ident.abs() },
+            ),
+            None => (
+                quote! {
+                    const #min_ident: #ty = *#range_ident.start();
+                    const #max_ident: #ty = *#range_ident.end();
+                },
+                quote! { 1 },
+            ),
+        };
+        quote! {
+            const #range_ident: std::ops::RangeInclusive<#ty> = #range;
+            #bound_defs
+            const #len_ident: usize = {
+                let len = (#max_ident as i128 - #min_ident as i128) / #step + 1;
+                assert!(len > 0, "precalculated range is empty");
+                assert!(
+                    len <= isize::MAX as i128,
+                    "precalculated range is too large for a look-up table"
+                );
+                len as usize
+            };
+            const #size_ident: usize = table_dimension(#len_ident);
+            #kind_defs
+        }
+    }
+
+    /// Expression checking that the argument is within its range.
+    pub fn in_range(&self) -> TokenStream {
+        let ident = &self.ident;
+        let min_ident = self.min_ident();
+        let max_ident = self.max_ident();
+        if let Some(Transform { to_index, .. }) = &self.transform {
+            return quote! {
+                #min_ident <= #to_index(#ident) && #to_index(#ident) <= #max_ident
+            };
+        }
+        match self.kind {
+            ArgKind::Int | ArgKind::Char => quote! { #min_ident <= #ident && #ident <= #max_ident },
+            // The marker is only forwarded to the original function.
+            ArgKind::Marker => quote! {
+                ({
+                    let _ = &#ident;
+                    true
+                })
+            },
+            ArgKind::Variants => {
+                let disc_min_ident = self.const_ident("DISC_MIN");
+                let disc_max_ident = self.const_ident("DISC_MAX");
+                let positions_ident = self.const_ident("POSITIONS");
+                quote! {
+                    ({
+                        let disc = #ident as isize;
+                        #disc_min_ident <= disc
+                            && disc <= #disc_max_ident
+                            && #positions_ident[(disc - #disc_min_ident) as usize] != usize::MAX
+                    })
+                }
+            }
+            ArgKind::Enum => quote! {
+                #min_ident as isize <= #ident as isize && #ident as isize <= #max_ident as isize
+            },
+        }
+    }
+
+    /// Expression checking, at compile time, that the range contains every
+    /// value of the argument's type. Transformed arguments never do, as their
+    /// index function may reject some values.
+    pub fn covers_domain(&self) -> TokenStream {
+        let ty = &self.ty;
+        let min_ident = self.min_ident();
+        let max_ident = self.max_ident();
+        if self.transform.is_some() {
+            return quote! { false };
+        }
+        match self.kind {
+            ArgKind::Int | ArgKind::Char => {
+                quote! { (#min_ident == <#ty>::MIN && #max_ident == <#ty>::MAX) }
+            }
+            ArgKind::Marker => quote! { true },
+            // The slice isn't required to list every variant.
+            ArgKind::Variants => quote! { false },
+            ArgKind::Enum => quote! {
+                (#min_ident as isize == <#ty as recuerdame::PrecalcEnum>::VARIANTS[0] as isize
+                    && #max_ident as isize
+                        == <#ty as recuerdame::PrecalcEnum>::VARIANTS
+                            [<#ty as recuerdame::PrecalcEnum>::VARIANTS.len() - 1] as isize)
+            },
+        }
+    }
+
+    /// Statement clamping the argument into its range, for `x = clamp(...)`.
+    pub fn clamp_input(&self) -> Option<TokenStream> {
+        let ident = &self.ident;
+        let min_ident = self.min_ident();
+        let max_ident = self.max_ident();
+        self.clamp.then(|| {
+            quote! {
+                let #ident = if #ident < #min_ident {
+                    #min_ident
+                } else if #ident > #max_ident {
+                    #max_ident
+                } else {
+                    #ident
+                };
+            }
+        })
+    }
+
+    /// Statement panicking with a descriptive message if the argument is out
+    /// of range.
+    pub fn range_assert(&self) -> TokenStream {
+        let ident = &self.ident;
+        let name = ident.to_string();
+        let in_range = self.in_range();
+        let panic = match self.kind {
+            ArgKind::Int if self.transform.is_none() => quote! {
+                recuerdame::__private::out_of_range(#name, #ident as i128)
+            },
+            _ => quote! { recuerdame::__private::variant_out_of_range(#name) },
+        };
+        quote! {
+            if !(#in_range) {
+                #panic
+            }
+        }
+    }
+
+    /// The unsigned integer type with the width of an integer argument,
+    /// `u128` for a type not spelled as a primitive, such as an alias.
+    fn unsigned_ty(&self) -> TokenStream {
+        match integer_name(&self.ty) {
+            Some(name) => {
+                let bits = name.trim_start_matches(['i', 'u']);
+                format_ident!("u{bits}").into_token_stream()
+            }
+            None => quote! { u128 },
+        }
+    }
+
+    /// Expression computing `to - from` in the unsigned type of the same
+    /// width, for `to >= from`. It can't wrap for unsigned types, and as the
+    /// difference may not fit a signed type (e.g. `127 - -128`) it is
+    /// computed with wrapping and reinterpreted for those. A type whose width
+    /// isn't known, such as an alias, goes through `i128` the same way.
+    fn offset(&self, to: TokenStream, from: TokenStream) -> TokenStream {
+        let unsigned = self.unsigned_ty();
+        match integer_name(&self.ty) {
+            Some(name) if name.starts_with('u') => quote! { #to.saturating_sub(#from) },
+            Some(_) => quote! { (#to.wrapping_sub(#from) as #unsigned) },
+            None => quote! { ((#to as i128).wrapping_sub(#from as i128) as u128) },
+        }
+    }
+
+    /// Expression computing the index of the (in range) argument.
+    pub fn index(&self) -> TokenStream {
+        let ident = &self.ident;
+        let min_ident = self.min_ident();
+        if let Some(Transform { to_index, .. }) = &self.transform {
+            return quote! { #to_index(#ident).saturating_sub(#min_ident) };
+        }
+        // Ranges starting at a literal 0 skip the subtraction.
+        let unsigned = self.unsigned_ty();
+        let offset = match self.starts_at_zero() {
+            true => quote! { #ident },
+            false => self.offset(quote! { #ident }, quote! { #min_ident }),
+        };
+        if self.step.is_some() {
+            // Inputs between two steps use the one closer to the start.
+            let step_ident = self.step_ident();
+            let start_ident = self.start_ident();
+            let descending_offset = self.offset(quote! { #start_ident }, quote! { #ident });
+            return quote! {
+                if #step_ident > 0 {
+                    (#offset as #unsigned / #step_ident as #unsigned) as usize
+                } else {
+                    (#descending_offset / (-#step_ident) as #unsigned) as usize
+                }
+            };
+        }
+        match self.kind {
+            ArgKind::Int => quote! { #offset as usize },
+            ArgKind::Char => quote! { (#ident as u32 - #min_ident as u32) as usize },
+            // The marker is only forwarded to the original function.
+            ArgKind::Marker => quote! { 0 },
+            ArgKind::Variants => {
+                let disc_min_ident = self.const_ident("DISC_MIN");
+                let positions_ident = self.const_ident("POSITIONS");
+                quote! { #positions_ident[(#ident as isize - #disc_min_ident) as usize] }
+            }
+            ArgKind::Enum => quote! { (#ident as isize - #min_ident as isize) as usize },
+        }
+    }
+
+    /// Like [`Arg::index`], but a stepped argument between two precalculated
+    /// values rounds to the nearest one, halfway values round up.
+    pub fn nearest_index(&self) -> TokenStream {
+        if self.step.is_none() {
+            return self.index();
+        }
+        let ident = &self.ident;
+        let start_ident = self.start_ident();
+        let step_ident = self.step_ident();
+        // Rounding up is away from the start when ascending, towards it when
+        // descending.
+        quote! {
+            {
+                let step = #step_ident.abs();
+                let offset = (#ident as i128 - #start_ident as i128) * #step_ident.signum();
+                let half = if #step_ident > 0 { step } else { step - 1 };
+                ((offset * 2 + half) / (step * 2)) as usize
+            }
+        }
+    }
+
+    /// Expression computing the argument value stored at `index`.
+    pub fn value(&self, index: &Ident) -> TokenStream {
+        let ty = &self.ty;
+        let min_ident = self.min_ident();
+        if let Some(Transform { from_index, .. }) = &self.transform {
+            return quote! { #from_index(#min_ident + #index) };
+        }
+        if self.step.is_some() {
+            let step_ident = self.step_ident();
+            let start_ident = self.start_ident();
+            return quote! {
+                if #step_ident > 0 {
+                    #start_ident.wrapping_add((#index as #ty).wrapping_mul(#step_ident as #ty))
+                } else {
+                    #start_ident.wrapping_sub((#index as #ty).wrapping_mul((-#step_ident) as #ty))
+                }
+            };
+        }
+        // Wrapping, as the index may not fit the signed type, the result does.
+        match self.kind {
+            ArgKind::Int => quote! { #min_ident.wrapping_add(#index as #ty) },
+            // Indices of surrogate code points, which no input maps to, are
+            // filled with the value of `min`.
+            ArgKind::Char => quote! {
+                match char::from_u32(#min_ident as u32 + #index as u32) {
+                    Some(value) => value,
+                    None => #min_ident,
+                }
+            },
+            ArgKind::Marker => quote! {{
+                let _ = #index;
+                #min_ident
+            }},
+            ArgKind::Variants => {
+                let values_ident = self.const_ident("VALUES");
+                quote! { #values_ident[#index] }
+            }
+            ArgKind::Enum => {
+                let offset_ident = self.const_ident("OFFSET");
+                quote! { <#ty as recuerdame::PrecalcEnum>::VARIANTS[#offset_ident + #index] }
+            }
+        }
+    }
+
+    /// The bounds of the range, if it is an integer range written with
+    /// literal bounds such as `-2..=5`.
+    fn literal_bounds(&self) -> Option<(Option<i128>, Option<i128>)> {
+        if self.kind != ArgKind::Int || self.transform.is_some() {
+            return None;
+        }
+        match syn::parse2::<Expr>(self.range.clone()).ok()? {
+            Expr::Range(range) if self.open || matches!(range.limits, RangeLimits::Closed(_)) => {
+                Some((
+                    range.start.as_deref().and_then(literal),
+                    range.end.as_deref().and_then(literal),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the range starts at a literal `0`, so no subtraction is needed
+    /// to compute the index.
+    fn starts_at_zero(&self) -> bool {
+        matches!(self.literal_bounds(), Some((Some(0), _)))
+    }
+
+    /// Whether the argument is a `u8` or `i8` whose literal range holds
+    /// every value of the type, e.g. `b = 0..=255`.
+    pub fn covers_byte(&self) -> bool {
+        if self.step.is_some() {
+            return false;
+        }
+        let full = if self.ty_is("u8") {
+            (0, 255)
+        } else if self.ty_is("i8") {
+            (-128, 127)
+        } else {
+            return false;
+        };
+        self.literal_bounds() == Some((Some(full.0), Some(full.1)))
+    }
+
+    fn ty_is(&self, name: &str) -> bool {
+        matches!(&*self.ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident(name))
+    }
+
+    /// The first and last values of the range, if it is an integer range
+    /// without a step written with literal bounds such as `-2..=5`.
+    pub fn literal_range(&self) -> Option<(i128, i128)> {
+        if self.step.is_some() {
+            return None;
+        }
+        match self.literal_bounds()? {
+            (Some(start), Some(end)) => Some((start, end)),
+            _ => None,
+        }
+    }
+}
+
+/// The value of an integer literal, possibly negated or parenthesized.
+pub fn literal(expr: &Expr) -> Option<i128> {
+    match expr {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Int(int) => int.base10_parse().ok(),
+            Lit::Byte(byte) => Some(byte.value() as i128),
+            _ => None,
+        },
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => {
+            literal(&unary.expr).map(|value| -value)
+        }
+        Expr::Paren(paren) => literal(&paren.expr),
+        Expr::Group(group) => literal(&group.expr),
+        _ => None,
+    }
+}
@@ -1,10 +1,20 @@
 extern crate proc_macro;
 
-use std::collections::{HashMap, HashSet};
+mod arg;
 
+use std::collections::HashMap;
+
+use arg::{Arg, ArgKind, Transform, literal};
 use proc_macro::TokenStream;
 use quote::{ToTokens, format_ident, quote};
-use syn::{FnArg, ItemFn, Meta, Pat, Token, Visibility, parse_macro_input, punctuated::Punctuated};
+use syn::{
+    Expr, ExprClosure, FnArg, GenericParam, Ident, ItemFn, Meta, Pat, Token, Visibility,
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    parse_macro_input, parse_quote,
+    punctuated::Punctuated,
+    visit_mut::{self, VisitMut},
+};
 
 /// Precalculate all possible values for const function at compile time.
 ///
@@ -12,294 +22,3737 @@ use syn::{FnArg, ItemFn, Meta, Pat, Token, Visibility, parse_macro_input, punctu
 /// having to run complicated arithmentic at runtime.
 ///
 /// This macro supports three operating modes:
-///  - **fallback** (Default): The fallback operating mode never panic (unless the implementation panics). It will use the look up table for the specified ranges and use the original implementation if outside of the range.
+///  - **fallback** (Default): The fallback operating mode never panic (unless the implementation panics). It will use the look up table for the specified ranges and use the original implementation if outside of the range. It can be written explicitly as `basic`, which can't be combined with another mode.
 ///  - **option**: The option operating mode will change the function to return an [Option]. [Some] if the input is in range, [None] if not.
-///  - **panic**: If the input is outside of the range specified in the macro the function will panic.
+///  - **panic**: If the input is outside of the range specified in the macro the function will panic, naming the argument and its value. The panic location is the caller's.
+///
+/// The option and fallback modes will require additional bounds checks which may come at a cost.
+///
+/// Each argument takes a range, such as `a = 0..=10`, `a = 0..10`,
+/// `a = start_count(0, 11)`, `a = 0..=100 by 10`, `a = clamp(0..=10)` or
+/// `c = ' '..='~'`. Arguments may be integers, `char`, enums given as
+/// `k = Kind::VARIANTS` or zero-sized markers given as `m = marker(Metric)`.
+///
+/// The original function is evaluated at compile time for every input in the
+/// ranges, so it must not panic for any of them. Please benchmark the
+/// functions to decide if it's worth using a look-up table.
 ///
-/// The option and keep modes will require additional bounds checks which may come at a cost.
+/// Alongside the function, these items are always generated:
+///  - `<name>_checked`: the index of each argument in the table, or [None].
+///  - `<name>_by_index`: reads the table from such indices.
+///  - `<name>_used_table`: whether a call reads the table.
+///  - `<name>_index_of` and `<name>_unindex`: offsets in the flattened table.
+///  - `<NAME>_DIMS` and `<NAME>_COVERS_FULL_DOMAIN`.
 ///
-/// Please benchmark the functions to decide if it's worth using a look-up table.
+/// Other options, described in the README:
+///  - `variants(option, panic, fallback, keep)`: more modes sharing the
+///    table, as `<name>_opt`, `<name>_panic`, `<name>_fallback` and
+///    `<name>_keep`.
+///  - `assume_in_range`: drops the bounds check, used instead of a mode.
+///  - `flatten_option`: returns `Option<T>` instead of `Option<Option<T>>`.
+///  - `name = add_small`, `T = i32`: name the function and instantiate a
+///    generic one, stacked attributes each generate their own table.
+///  - `defaults(b = 2)`: generates `<name>_b2` with `b` fixed.
+///  - `export`, `opaque_table`: expose the table as `<name>_table()`.
+///  - `packed`: flat view of array tables, bits for `bool` tables.
+///  - `flat_table`, `build_table`: copies of the table built at run time.
+///  - `storage = const | static | heap`, `link_section = ".lookup"`.
+///  - `values = VALUES`, `fill = EXPR`, `jagged`, `store = f16`,
+///    `recurrence(depends_on = [n - 1])`, `transform(x = (to, from))`,
+///    `ranges_const = RANGES`: how the table is populated and stored.
+///  - `max_bytes = N`: the size limit of the table, 16 MiB by default.
+///  - `small = match`, `inline = never | always`, `check_order = [b, a]`,
+///    `rt`: how the table is read.
+///  - `release_only`: calls the original function in debug builds.
+///  - `count_misses`, `value_stats`, `expect_hash = "0x..."`,
+///    `label = "..."`: statistics and checks of the table.
+///  - `cfg = PREDICATE`, `c_abi = "name"`, `self_value = EXPR`.
 ///
 /// Examples:
 /// ```rust
 /// use recuerdame::precalculate;
 ///
-/// #[precalculate(a = 0..=10, b = 0..=4)]
+/// #[precalculate(a = 0..=10, b = 0..=4, panic, variants(option, keep))]
 /// pub const fn add(a: i32, b: i32) -> i32 {
 ///     a + b
 /// }
 ///
-/// #[precalculate(a = 0..=10, b = 0..=4, option)]
-/// pub const fn add_opt(a: i32, b: i32) -> i32 {
-///     a + b
-/// }
+/// assert_eq!(add(8, 2), 10);
+/// assert_eq!(add_opt(5, 4), Some(9));
+/// assert_eq!(add_opt(25, 0), None);
+/// assert_eq!(add_keep(25, 0), 25);
+/// ```
 ///
+/// ```rust,should_panic
+/// # use recuerdame::precalculate;
 /// #[precalculate(a = 0..=10, b = 0..=4, panic)]
-/// pub const fn add_panic(a: i32, b: i32) -> i32 {
+/// pub const fn add(a: i32, b: i32) -> i32 {
 ///     a + b
 /// }
 ///
-/// #[test]
-/// fn it_works() {
-///     assert_eq!(add(8, 2), 10);
-///     assert_eq!(add(0, 0), 0);
-///     assert_eq!(add_keep(5, 4), 9);
-///     assert_eq!(add_keep(25, 0), 25);
+/// add(25, 9);
+/// ```
+#[proc_macro_attribute]
+pub fn precalculate(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let metas: Punctuated<Meta, Token![,]> = parse_macro_input!(attr with parse_options);
+    let mut func = parse_macro_input!(item as ItemFn);
+
+    // Further `#[precalculate]` attributes each apply to their own copy of
+    // the function, so stacked attributes generate independent tables.
+    let (stacked, attrs) = func.attrs.into_iter().partition::<Vec<_>, _>(|attr| {
+        attr.path()
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "precalculate")
+    });
+    func.attrs = attrs;
+    let copies = stacked
+        .iter()
+        .map(|attr| quote! { #attr #func })
+        .collect::<Vec<_>>();
+
+    let expanded = expand(metas, func).unwrap_or_else(syn::Error::into_compile_error);
+    quote! {
+        #expanded
+        #(#copies)*
+    }
+    .into()
+}
+
+/// Precalculate all possible values for a const closure at compile time.
+///
+/// This is the function-like counterpart of [`macro@precalculate`] for
+/// look-up logic written as a closure rather than a `const fn`. It takes
+/// the name of the function to generate, a closure with typed arguments
+/// and an explicit return type, followed by the same options accepted by
+/// [`macro@precalculate`].
+///
+/// The closure body is turned into a `const fn` with the given name, so
+/// it must only use const-compatible code.
+///
+/// Examples:
+/// ```rust
+/// use recuerdame::precalculate_closure;
+///
+/// precalculate_closure!(pub double, |x: u8| -> u16 { x as u16 * 2 }, x = 0..=255);
+///
+/// assert_eq!(double(7), 14);
+/// ```
+#[proc_macro]
+pub fn precalculate_closure(input: TokenStream) -> TokenStream {
+    let closure = parse_macro_input!(input as PrecalcClosure);
+    let (metas, func) = closure.into_parts();
+    expand(metas, func)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Fuses two functions into a single precalculated function.
+///
+/// `fuse!(gf(x: T) -> R, g, f, options...)` generates `gf`, whose look-up
+/// table stores `g(f(x))` for every `x` in range, so computing it takes one
+/// look-up instead of two. `f` takes the arguments of the fused function and
+/// `g` the value returned by `f`. They can be precalculated functions, as
+/// long as their modes keep the types in line (e.g. not `option` for `f`).
+/// The options are the same as for [`macro@precalculate`].
+///
+/// Examples:
+/// ```rust
+/// use recuerdame::{fuse, precalculate};
+///
+/// #[precalculate(x = 0..=10)]
+/// const fn square(x: u32) -> u32 {
+///     x * x
 /// }
 ///
-/// #[test]
-/// fn it_works_opt() {
-///     assert_eq!(add_opt(5, 4), Some(9));
-///     assert_eq!(add_opt(25, 0), None);
+/// #[precalculate(x = 0..=100)]
+/// const fn half(x: u32) -> u32 {
+///     x / 2
 /// }
 ///
-/// #[test]
-/// #[should_panic]
-/// fn outside_bounds_panics() {
-///     add_panic(25, 9);
+/// fuse!(pub half_square(x: u32) -> u32, half, square, x = 0..=10);
+///
+/// fn main() {
+///     assert_eq!(half_square(5), 12);
 /// }
 /// ```
-#[proc_macro_attribute]
-pub fn precalculate(attr: TokenStream, item: TokenStream) -> TokenStream {
-    let metas: Punctuated<Meta, Token![,]> =
-        parse_macro_input!(attr with Punctuated::parse_terminated);
+#[proc_macro]
+pub fn fuse(input: TokenStream) -> TokenStream {
+    let fuse = parse_macro_input!(input as Fuse);
+    let (metas, func) = fuse.into_parts();
+    expand(metas, func)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
-    #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
-    enum Options {
-        Fallback,
-        Option,
-        Panic,
-    }
+/// Gathers several precalculated functions into the methods of a unit
+/// struct, giving related look-up tables, such as the transitions of a state
+/// machine, a single namespace.
+///
+/// Each function takes its own `#[precalculate(...)]` attribute with the
+/// same options as [`macro@precalculate`]. It becomes a `const` method
+/// taking `&self`, with the visibility of the function and the return type
+/// of its operating mode, as if precalculated with `self_value` set to the
+/// struct. As for other precalculated methods, the companion functions such
+/// as `<name>_checked` are not generated.
+///
+/// Examples:
+/// ```rust
+/// use recuerdame::precalculate_struct;
+///
+/// precalculate_struct! {
+///     pub struct Arithmetic {
+///         #[precalculate(a = 0..=10, b = 0..=4)]
+///         pub const fn add(a: i32, b: i32) -> i32 {
+///             a + b
+///         }
+///
+///         #[precalculate(a = 0..=10, option)]
+///         pub const fn double(a: i32) -> i32 {
+///             a * 2
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///     assert_eq!(Arithmetic.add(5, 2), 7);
+///     assert_eq!(Arithmetic.double(20), None);
+/// }
+/// ```
+#[proc_macro]
+pub fn precalculate_struct(input: TokenStream) -> TokenStream {
+    let PrecalcStruct {
+        attrs,
+        vis,
+        ident,
+        funcs,
+    } = parse_macro_input!(input as PrecalcStruct);
+    let methods = funcs
+        .into_iter()
+        .map(|mut func| {
+            let position = func
+                .attrs
+                .iter()
+                .position(|attr| attr.path().is_ident("precalculate"))
+                .ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        &func.sig.ident,
+                        "every function of precalculate_struct! needs a `#[precalculate(...)]` attribute",
+                    )
+                })?;
+            let attr = func.attrs.remove(position);
+            let mut metas = attr.parse_args_with(parse_options)?;
+            metas.push(parse_quote! { self_value = #ident });
+            func.sig.inputs.insert(0, parse_quote! { &self });
+            expand(metas, func)
+        })
+        .collect::<syn::Result<Vec<_>>>();
+    match methods {
+        Ok(methods) => quote! {
+            #(#attrs)*
+            #vis struct #ident;
 
-    let mut mode = Vec::new();
-    let mut range_map = HashMap::<String, proc_macro2::TokenStream>::new();
-    for meta in metas {
-        match meta {
-            Meta::NameValue(mnv) => {
-                let ident = mnv
-                    .path
-                    .get_ident()
-                    .expect("Attribute key must be an identifier")
-                    .to_string();
-                let value_expr = mnv.value.into_token_stream();
-                if range_map.insert(ident.clone(), value_expr).is_some() {
-                    panic!("Duplicated key: {ident}");
-                }
-            }
-            Meta::Path(opt) => {
-                match opt.to_token_stream().to_string().trim() {
-                    "option" => mode.push(Options::Option),
-                    "panic" => mode.push(Options::Panic),
-                    "fallback" => mode.push(Options::Fallback),
-                    opt => panic!("Unknown option: {opt}"),
-                };
+            impl #ident {
+                #(#methods)*
             }
-            _ => (),
-        }
+        },
+        Err(error) => error.into_compile_error(),
     }
+    .into()
+}
 
-    let mode = match mode.len() {
-        0 => Options::Fallback,
-        1 => mode[0],
-        _ => {
-            panic!(
-                "precalculate macro may only take one operating mode at a time, found: {:?}.",
-                mode
-            )
+/// Asserts that a precalculated function returns the same value as the
+/// original function for the given arguments.
+///
+/// This is the differential check of a fuzz target or property test. The
+/// function must use the `fallback` or `panic` mode, as `option` changes the
+/// return type. The original function is only visible in the module that
+/// defines the precalculated function and its children.
+///
+/// Examples:
+/// ```rust
+/// use recuerdame::{precalc_assert_eq, precalculate};
+///
+/// #[precalculate(a = 0..=10, b = 0..=4)]
+/// const fn add(a: i32, b: i32) -> i32 {
+///     a + b
+/// }
+///
+/// precalc_assert_eq!(add, (3, 4));
+/// precalc_assert_eq!(add, (30, -4));
+/// ```
+#[proc_macro]
+pub fn precalc_assert_eq(input: TokenStream) -> TokenStream {
+    let PrecalcAssertEq {
+        func,
+        args,
+        epsilon,
+    } = parse_macro_input!(input as PrecalcAssertEq);
+    if let Some(epsilon) = epsilon {
+        return syn::Error::new_spanned(
+            epsilon,
+            "`verify_epsilon` compares floats, use `precalc_assert_approx_eq!`",
+        )
+        .into_compile_error()
+        .into();
+    }
+    let original = original_path(&func);
+    let indices = (0..args.elems.len()).map(syn::Index::from);
+    let indices_original = indices.clone();
+    quote! {
+        {
+            let args = #args;
+            assert_eq!(
+                #func(#(args.#indices),*),
+                #original(#(args.#indices_original),*),
+                "`{}` differs from the original function",
+                stringify!(#func),
+            );
         }
-    };
+    }
+    .into()
+}
 
-    let mut func = parse_macro_input!(item as ItemFn);
-    let visibility = func.vis.clone();
-    let func_ident = func.sig.ident.clone();
-    let new_func_ident = format_ident!("_{func_ident}_original");
-    func.vis = Visibility::Public(syn::token::Pub::default());
-    func.sig.ident = new_func_ident.clone();
-    let func_return_type = &func.sig.output;
-    let mut return_ty = match func_return_type {
-        syn::ReturnType::Default => panic!("Function must have a return type."),
-        syn::ReturnType::Type(_, ty) => ty.clone(),
+/// Asserts that a precalculated function returning a float is within an
+/// epsilon of the original function for the given arguments.
+///
+/// This is [`macro@precalc_assert_eq`] for `f32` and `f64` return types,
+/// comparing the values with `recuerdame::approx_eq`. The epsilon defaults
+/// to `recuerdame::DEFAULT_EPSILON` (`1e-6`) and can be set with
+/// `verify_epsilon = EPSILON`.
+///
+/// Examples:
+/// ```rust
+/// use recuerdame::{precalc_assert_approx_eq, precalculate};
+///
+/// #[precalculate(x = 0..=100)]
+/// const fn scale(x: u8) -> f32 {
+///     x as f32 * 0.1
+/// }
+///
+/// precalc_assert_approx_eq!(scale, (42));
+/// precalc_assert_approx_eq!(scale, (200), verify_epsilon = 1e-3);
+/// ```
+#[proc_macro]
+pub fn precalc_assert_approx_eq(input: TokenStream) -> TokenStream {
+    let PrecalcAssertEq {
+        func,
+        args,
+        epsilon,
+    } = parse_macro_input!(input as PrecalcAssertEq);
+    let original = original_path(&func);
+    let epsilon = match epsilon {
+        Some(epsilon) => quote! { #epsilon },
+        None => quote! { recuerdame::DEFAULT_EPSILON },
     };
-
-    let mut arg_info = Vec::new();
-    for arg in &func.sig.inputs {
-        if let FnArg::Typed(pat_type) = arg
-            && let Pat::Ident(pat_ident) = &*pat_type.pat
+    let indices = (0..args.elems.len()).map(syn::Index::from);
+    let indices_original = indices.clone();
+    quote! {
         {
-            let arg_name = pat_ident.ident.to_string();
-            let arg_type = &pat_type.ty;
-            if let Some(range_expr) = range_map.get(&arg_name) {
-                arg_info.push((
-                    pat_ident.ident.clone(),
-                    arg_type.clone(),
-                    range_expr.clone(),
+            let args = #args;
+            let precalculated = #func(#(args.#indices),*);
+            let original = #original(#(args.#indices_original),*);
+            assert!(
+                recuerdame::approx_eq(precalculated, original, #epsilon),
+                "`{}` differs from the original function: {:?} != {:?}",
+                stringify!(#func),
+                precalculated,
+                original,
+            );
+        }
+    }
+    .into()
+}
+
+/// The path of the original function of the precalculated function `func`.
+fn original_path(func: &syn::Path) -> syn::Path {
+    let mut original = func.clone();
+    let ident = original.segments.pop().unwrap().into_value().ident;
+    original
+        .segments
+        .push(format_ident!("_mod_precalc_{ident}").into());
+    original
+        .segments
+        .push(format_ident!("_{ident}_original").into());
+    original
+}
+
+/// Input of [`macro@precalc_assert_eq`] and [`macro@precalc_assert_approx_eq`]:
+/// `path::to::func, (args...)`, optionally followed by `verify_epsilon = EPSILON`.
+struct PrecalcAssertEq {
+    func: syn::Path,
+    args: syn::ExprTuple,
+    epsilon: Option<Expr>,
+}
+
+impl Parse for PrecalcAssertEq {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let func = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let args = match input.parse()? {
+            Expr::Tuple(args) => args,
+            Expr::Paren(paren) => syn::ExprTuple {
+                attrs: paren.attrs,
+                paren_token: paren.paren_token,
+                elems: std::iter::once(*paren.expr).collect(),
+            },
+            expr => {
+                return Err(syn::Error::new_spanned(
+                    expr,
+                    "expected the arguments as a tuple, e.g. `(a, b)`",
                 ));
-            } else {
-                panic!("Argument '{arg_name}' does not have a specified range.");
             }
+        };
+        let mut epsilon = None;
+        if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let option = input.parse::<syn::MetaNameValue>()?;
+            if !option.path.is_ident("verify_epsilon") {
+                return Err(syn::Error::new_spanned(
+                    option.path,
+                    "expected `verify_epsilon = EPSILON`",
+                ));
+            }
+            epsilon = Some(option.value);
+            input.parse::<Option<Token![,]>>()?;
         }
+        Ok(PrecalcAssertEq {
+            func,
+            args,
+            epsilon,
+        })
+    }
+}
+
+/// Input of [`macro@precalculate_closure`]: `vis name, |args| -> ret { body }, options...`
+struct PrecalcClosure {
+    vis: Visibility,
+    ident: Ident,
+    closure: ExprClosure,
+    metas: Punctuated<Meta, Token![,]>,
+}
+
+impl Parse for PrecalcClosure {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let vis = input.parse()?;
+        let ident = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let closure = input.parse()?;
+        let metas = if input.is_empty() {
+            Punctuated::new()
+        } else {
+            input.parse::<Token![,]>()?;
+            parse_options(input)?
+        };
+        Ok(PrecalcClosure {
+            vis,
+            ident,
+            closure,
+            metas,
+        })
     }
+}
 
-    let const_defs = arg_info.iter().map(|(ident, ty, range_expr)| {
-        let upper_ident = ident.to_string().to_uppercase();
-        let range_ident = format_ident!("{}_RANGE", upper_ident);
-        let min_ident = format_ident!("{}_MIN", upper_ident);
-        let max_ident = format_ident!("{}_MAX", upper_ident);
-        let size_ident = format_ident!("{}_SIZE", upper_ident);
+/// Input of [`macro@fuse`]: `vis name(args) -> ret, outer, inner, options...`
+struct Fuse {
+    vis: Visibility,
+    ident: Ident,
+    inputs: Punctuated<FnArg, Token![,]>,
+    output: syn::ReturnType,
+    outer: syn::Path,
+    inner: syn::Path,
+    metas: Punctuated<Meta, Token![,]>,
+}
 
-        quote! {
-            const #range_ident: std::ops::RangeInclusive<#ty> = #range_expr;
-            const #min_ident: #ty = *#range_ident.start();
-            const #max_ident: #ty = *#range_ident.end();
-            const #size_ident: usize = (#max_ident as isize - #min_ident as isize + 1) as usize;
+impl Parse for Fuse {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let vis = input.parse()?;
+        let ident = input.parse()?;
+        let args;
+        syn::parenthesized!(args in input);
+        let inputs = args.parse_terminated(FnArg::parse, Token![,])?;
+        let output = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let outer = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let inner = input.parse()?;
+        let metas = if input.is_empty() {
+            Punctuated::new()
+        } else {
+            input.parse::<Token![,]>()?;
+            parse_options(input)?
+        };
+        Ok(Fuse {
+            vis,
+            ident,
+            inputs,
+            output,
+            outer,
+            inner,
+            metas,
+        })
+    }
+}
+
+/// Input of [`macro@precalculate_struct`]: `vis struct Name { functions... }`
+struct PrecalcStruct {
+    attrs: Vec<syn::Attribute>,
+    vis: Visibility,
+    ident: Ident,
+    funcs: Vec<ItemFn>,
+}
+
+impl Parse for PrecalcStruct {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(syn::Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        input.parse::<Token![struct]>()?;
+        let ident = input.parse()?;
+        let content;
+        syn::braced!(content in input);
+        let mut funcs = Vec::new();
+        while !content.is_empty() {
+            funcs.push(content.parse()?);
         }
-    });
+        Ok(PrecalcStruct {
+            attrs,
+            vis,
+            ident,
+            funcs,
+        })
+    }
+}
 
-    let table_type = arg_info
-        .iter()
-        .rev()
-        .fold(quote! { #return_ty }, |inner, (ident, _, _)| {
-            let size_ident = format_ident!("{}_SIZE", ident.to_string().to_uppercase());
-            quote! { [#inner; #size_ident] }
+impl Fuse {
+    fn into_parts(self) -> (Punctuated<Meta, Token![,]>, ItemFn) {
+        let Fuse {
+            vis,
+            ident,
+            inputs,
+            output,
+            outer,
+            inner,
+            metas,
+        } = self;
+        let args = inputs.iter().filter_map(|arg| match arg {
+            FnArg::Typed(pat_type) => Some(&pat_type.pat),
+            FnArg::Receiver(_) => None,
         });
+        let func = parse_quote! {
+            #vis const fn #ident(#inputs) #output {
+                #outer(#inner(#(#args),*))
+            }
+        };
+        (metas, func)
+    }
+}
 
-    let func_args = arg_info.iter().map(|(ident, _, _)| ident);
+mod kw {
+    syn::custom_keyword!(by);
+}
 
-    let generate_table_fn = {
-        let table_init_value = quote! { recuerdame::PrecalcConst::DEFAULT };
-        let table_init_expr =
-            arg_info
-                .iter()
-                .rev()
-                .fold(table_init_value, |inner, (ident, _, _)| {
-                    let size_ident = format_ident!("{}_SIZE", ident.to_string().to_uppercase());
-                    quote! { [#inner; #size_ident] }
-                });
+/// A range with a stride between its values: `0..=100 by 10`.
+struct SteppedRange {
+    range: Expr,
+    step: Expr,
+}
 
-        let mut nested_loops = {
-            let value_calcs = arg_info.iter().map(|(ident, ty, _)| {
-                let min_ident = format_ident!("{}_MIN", ident.to_string().to_uppercase());
-                let loop_var = format_ident!("{}_idx", ident);
-                quote! { let #ident = #min_ident + #loop_var as #ty; }
-            });
-            let table_access = arg_info
-                .iter()
-                .fold(quote! { table }, |acc, (ident, _, _)| {
-                    let loop_var = format_ident!("{}_idx", ident);
-                    quote! { #acc[#loop_var] }
-                });
+impl Parse for SteppedRange {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let range = input.parse()?;
+        input.parse::<kw::by>()?;
+        let step = input.parse()?;
+        Ok(SteppedRange { range, step })
+    }
+}
 
-            let func_args = func_args.clone();
+/// Parses the comma separated options of the macros.
+///
+/// These are regular [Meta] items, except that `key = value` also accepts a
+/// bare keyword as the value (e.g. `storage = static`) or a stepped range
+/// (e.g. `x = 0..=100 by 10`), which are stored as an [`Expr::Verbatim`].
+fn parse_options(input: ParseStream) -> syn::Result<Punctuated<Meta, Token![,]>> {
+    fn parse_keyword_value(input: ParseStream) -> syn::Result<Meta> {
+        let path = input.parse()?;
+        let eq_token = input.parse()?;
+        let value = Expr::Verbatim(Ident::parse_any(input)?.into_token_stream());
+        if !is_keyword_value(&value) || !(input.is_empty() || input.peek(Token![,])) {
+            return Err(input.error("expected a keyword value"));
+        }
+        Ok(Meta::NameValue(syn::MetaNameValue {
+            path,
+            eq_token,
+            value,
+        }))
+    }
 
-            quote! {
-                #(#value_calcs)*
-                #table_access = #new_func_ident(#(#func_args),*);
-            }
+    fn parse_stepped_range(input: ParseStream) -> syn::Result<Meta> {
+        let path = input.parse()?;
+        let eq_token = input.parse()?;
+        let SteppedRange { range, step } = input.parse()?;
+        if !(input.is_empty() || input.peek(Token![,])) {
+            return Err(input.error("expected `,`"));
+        }
+        Ok(Meta::NameValue(syn::MetaNameValue {
+            path,
+            eq_token,
+            value: Expr::Verbatim(quote! { #range by #step }),
+        }))
+    }
+
+    let mut options = Punctuated::new();
+    while !input.is_empty() {
+        let option = if parse_keyword_value(&input.fork()).is_ok() {
+            parse_keyword_value(input)?
+        } else if parse_stepped_range(&input.fork()).is_ok() {
+            parse_stepped_range(input)?
+        } else {
+            input.parse()?
         };
+        options.push_value(option);
+        if input.is_empty() {
+            break;
+        }
+        options.push_punct(input.parse()?);
+    }
+    Ok(options)
+}
 
-        for (ident, _, _) in arg_info.iter().rev() {
-            let loop_var = format_ident!("{}_idx", ident);
-            let size_ident = format_ident!("{}_SIZE", ident.to_string().to_uppercase());
-            nested_loops = quote! {
-                let mut #loop_var: usize = 0;
-                while #loop_var < #size_ident {
-                    #nested_loops
-                    #loop_var += 1;
+/// Finds the identifiers of every lifetime (e.g. `a` in `'a`) in `tokens`.
+fn find_lifetimes(tokens: proc_macro2::TokenStream) -> Vec<Ident> {
+    let mut lifetimes = Vec::new();
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            proc_macro2::TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                if let Some(proc_macro2::TokenTree::Ident(ident)) = tokens.peek() {
+                    lifetimes.push(ident.clone());
                 }
-            };
+            }
+            proc_macro2::TokenTree::Group(group) => {
+                lifetimes.extend(find_lifetimes(group.stream()))
+            }
+            _ => (),
         }
+    }
+    lifetimes
+}
 
-        quote! {
-            const fn generate_table() -> #table_type {
-                let mut table = #table_init_expr;
-                #nested_loops
-                table
-            }
+/// Suffix naming the value of an argument fixed by `defaults(...)`: `2`,
+/// `neg2` for `-2`, `true`, or `_less` for a path such as `Ordering::Less`.
+fn default_suffix(value: &Expr) -> syn::Result<String> {
+    match value {
+        Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(int),
+            ..
+        }) => Ok(int.base10_digits().to_string()),
+        Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Bool(bool),
+            ..
+        }) => Ok(format!("_{}", bool.value)),
+        Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => Ok(format!("neg{}", default_suffix(expr)?)),
+        Expr::Path(path) => Ok(format!(
+            "_{}",
+            path.path
+                .segments
+                .last()
+                .unwrap()
+                .ident
+                .to_string()
+                .to_lowercase()
+        )),
+        value => Err(syn::Error::new_spanned(
+            value,
+            "defaults values must be integer or bool literals, or paths",
+        )),
+    }
+}
+
+/// Rewrites `start_count(START, N)`, the `N` values from `START`, to the
+/// range `START..=START + N - 1`. Other values are returned unchanged.
+fn start_count_range(value: Expr) -> syn::Result<proc_macro2::TokenStream> {
+    let Expr::Call(call) = &value else {
+        return Ok(value.into_token_stream());
+    };
+    if !matches!(&*call.func, Expr::Path(path) if path.path.is_ident("start_count")) {
+        return Ok(value.into_token_stream());
+    }
+    let (Some(start), Some(count), None) = (call.args.get(0), call.args.get(1), call.args.get(2))
+    else {
+        return Err(syn::Error::new_spanned(
+            call,
+            "start_count expects a start and a number of values, e.g. `start_count(0, 11)`",
+        ));
+    };
+    let end = match (literal(start), literal(count)) {
+        (Some(start), Some(count)) => {
+            let end = start + count - 1;
+            let literal = proc_macro2::Literal::i128_unsuffixed(end.abs());
+            let sign = (end < 0).then(|| quote! { - });
+            quote! { #sign #literal }
         }
+        _ => quote! { (#start) + (#count) - 1 },
     };
+    Ok(quote! { #start..=#end })
+}
 
-    let mod_name = format_ident!("_mod_precalc_{}", func_ident);
+/// Unwraps `clamp(RANGE)`, returning the range and whether it is clamped.
+fn clamp_range(value: Expr) -> syn::Result<(Expr, bool)> {
+    let Expr::Call(call) = &value else {
+        return Ok((value, false));
+    };
+    if !matches!(&*call.func, Expr::Path(path) if path.path.is_ident("clamp")) {
+        return Ok((value, false));
+    }
+    match (call.args.first(), call.args.len()) {
+        (Some(range), 1) => Ok((range.clone(), true)),
+        _ => Err(syn::Error::new_spanned(
+            call,
+            "clamp expects a single range, e.g. `clamp(0..=10)`",
+        )),
+    }
+}
 
-    let precalc_fn = {
-        let lookup_table_ident =
-            format_ident!("LOOKUP_TABLE_{}", func_ident.to_string().to_uppercase());
+/// Options written as a bare word, such as `option` or `export`.
+const FLAG_OPTIONS: [&str; 16] = [
+    "fallback",
+    "option",
+    "panic",
+    "basic",
+    "export",
+    "opaque_table",
+    "build_table",
+    "flat_table",
+    "flatten_option",
+    "count_misses",
+    "packed",
+    "jagged",
+    "release_only",
+    "value_stats",
+    "assume_in_range",
+    "rt",
+];
 
-        let fn_params = arg_info.iter().map(|(ident, ty, _)| quote! { #ident: #ty });
-        let index_calcs = arg_info.iter().map(|(ident, _ty, _)| {
-            let min_ident = format_ident!("{}_MIN", ident.to_string().to_uppercase());
-            let index_var = format_ident!("{}_idx", ident);
-            quote! { let #index_var = (#ident - #min_ident) as usize; }
-        });
+/// Options written `option = value`. Other keys name arguments.
+const NAME_VALUE_OPTIONS: [&str; 16] = [
+    "check_order",
+    "fill",
+    "cfg",
+    "expect_hash",
+    "label",
+    "values",
+    "name",
+    "small",
+    "max_bytes",
+    "self_value",
+    "inline",
+    "ranges_const",
+    "store",
+    "storage",
+    "link_section",
+    "c_abi",
+];
 
-        let bounds_check_expr = {
-            let per_ident_check = arg_info.iter().map(|(ident, _ty, _)| {
-                let min_ident = format_ident!("{}_MIN", ident.to_string().to_uppercase());
-                let max_ident = format_ident!("{}_MAX", ident.to_string().to_uppercase());
-                quote! { #min_ident <= #ident && #ident <= #max_ident }
-            });
+/// Options written `option(...)`.
+const LIST_OPTIONS: [&str; 4] = ["transform", "recurrence", "defaults", "variants"];
 
-            quote! { #(#per_ident_check &&)* true }
-        };
+/// Error for an unknown `what`, such as an option, suggesting the closest
+/// of `options`.
+fn unknown_option<'a>(
+    what: &str,
+    path: &syn::Path,
+    options: impl IntoIterator<Item = &'a str>,
+) -> syn::Error {
+    let name = path.to_token_stream().to_string();
+    let closest = options
+        .into_iter()
+        .map(|option| (edit_distance(&name, option), option))
+        .min()
+        .filter(|(distance, _)| *distance <= 2);
+    let message = match closest {
+        Some((_, option)) => format!("unknown {what} `{name}`, did you mean `{option}`?"),
+        None => format!("unknown {what} `{name}`"),
+    };
+    syn::Error::new_spanned(path, message)
+}
 
-        let mut table_access =
-            arg_info
-                .iter()
-                .fold(quote! { #lookup_table_ident }, |acc, (ident, _, _)| {
-                    let index_var = format_ident!("{}_idx", ident);
-                    quote! { #acc[#index_var] }
-                });
+/// Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
 
-        let mode_check = match mode {
-            Options::Panic => None,
-            Options::Fallback => Some(quote! {
-                if !(#bounds_check_expr) {
-                    return #new_func_ident(#(#func_args),*);
-                }
-            }),
-            Options::Option => {
-                // Change signature to return option
-                *return_ty.as_mut() = syn::Type::Verbatim(quote! { Option<#return_ty> });
-                // Change the table access expression to return Some
-                table_access = quote! { Some(#table_access)};
-                Some(quote! {
-                    if !(#bounds_check_expr) {
-                        return None;
-                    }
-                })
-            }
-        };
+/// Unwraps `marker(EXPR)`, the single value of a zero-sized argument.
+fn marker_value(value: &Expr) -> syn::Result<Option<Expr>> {
+    let Expr::Call(call) = value else {
+        return Ok(None);
+    };
+    if !matches!(&*call.func, Expr::Path(path) if path.path.is_ident("marker")) {
+        return Ok(None);
+    }
+    match (call.args.first(), call.args.len()) {
+        (Some(value), 1) => Ok(Some(value.clone())),
+        _ => Err(syn::Error::new_spanned(
+            call,
+            "marker expects the value of the argument, e.g. `marker(Metric)`",
+        )),
+    }
+}
 
-        quote! {
-            pub const fn #func_ident(#(#fn_params),*) -> #return_ty {
-                #mode_check
-                #(#index_calcs)*
-                #table_access
+/// Replaces the type parameters given a concrete type, such as `T = i32`,
+/// in the whole function and returns the remaining options.
+fn instantiate(
+    metas: Punctuated<Meta, Token![,]>,
+    func: ItemFn,
+) -> syn::Result<(Punctuated<Meta, Token![,]>, ItemFn)> {
+    let params = func
+        .sig
+        .generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+    let mut substitutions = Vec::new();
+    let mut options = Punctuated::new();
+    for meta in metas {
+        match meta {
+            Meta::NameValue(mnv)
+                if mnv
+                    .path
+                    .get_ident()
+                    .is_some_and(|ident| params.contains(ident)) =>
+            {
+                let ty: syn::Type = syn::parse2(mnv.value.to_token_stream())?;
+                substitutions.push((mnv.path.get_ident().unwrap().clone(), ty));
             }
+            meta => options.push(meta),
         }
-    };
+    }
+    if let Some(param) = params
+        .iter()
+        .find(|param| !substitutions.iter().any(|(ident, _)| ident == *param))
+    {
+        return Err(syn::Error::new_spanned(
+            param,
+            format!(
+                "the look-up table can't be generic, instantiate `{param}` with a concrete type, \
+                 e.g. `{param} = i32`"
+            ),
+        ));
+    }
+    if substitutions.is_empty() {
+        return Ok((options, func));
+    }
 
-    let lookup_table_ident =
-        format_ident!("LOOKUP_TABLE_{}", func_ident.to_string().to_uppercase());
-    let expanded = quote! {
+    // Inline bounds move to the where clause, so `T: Trait` is still checked
+    // once `T` is replaced by the concrete type.
+    let mut func = func;
+    let generics = &mut func.sig.generics;
+    let mut predicates = Vec::<syn::WherePredicate>::new();
+    generics.params = std::mem::take(&mut generics.params)
+        .into_iter()
+        .filter(|param| match param {
+            GenericParam::Type(param) => {
+                let (ident, bounds) = (&param.ident, &param.bounds);
+                if !bounds.is_empty() {
+                    predicates.push(parse_quote! { #ident: #bounds });
+                }
+                false
+            }
+            _ => true,
+        })
+        .collect();
+    generics.make_where_clause().predicates.extend(predicates);
+    SubstituteTypes(&substitutions).visit_item_fn_mut(&mut func);
+    Ok((options, func))
+}
 
-        mod #mod_name {
+/// Replaces type parameters by their concrete type, such as `T` by `i32`.
+/// Only types and paths starting with the parameter, e.g. `T::MAX`, are
+/// rewritten, a value or a variant that happens to be named `T` is not.
+struct SubstituteTypes<'a>(&'a [(Ident, syn::Type)]);
 
-            use super::*;
+impl SubstituteTypes<'_> {
+    fn concrete(&self, ident: &Ident) -> Option<&syn::Type> {
+        self.0
+            .iter()
+            .find(|(param, _)| param == ident)
+            .map(|(_, ty)| ty)
+    }
 
-            #func
+    /// Rewrites `T::ITEM` to `<i32>::ITEM`.
+    fn qualify(&self, qself: &mut Option<syn::QSelf>, path: &mut syn::Path) {
+        if qself.is_some() || path.leading_colon.is_some() || path.segments.len() < 2 {
+            return;
+        }
+        let first = &path.segments[0];
+        if !first.arguments.is_none() {
+            return;
+        }
+        let Some(ty) = self.concrete(&first.ident) else {
+            return;
+        };
+        *qself = Some(syn::QSelf {
+            lt_token: Default::default(),
+            ty: Box::new(ty.clone()),
+            position: 0,
+            as_token: None,
+            gt_token: Default::default(),
+        });
+        path.leading_colon = Some(Default::default());
+        path.segments = std::mem::take(&mut path.segments)
+            .into_pairs()
+            .skip(1)
+            .collect();
+    }
+}
 
-            #(#const_defs)*
+impl VisitMut for SubstituteTypes<'_> {
+    fn visit_type_mut(&mut self, ty: &mut syn::Type) {
+        if let syn::Type::Path(path) = ty
+            && path.qself.is_none()
+            && let Some(ident) = path.path.get_ident()
+            && let Some(concrete) = self.concrete(ident)
+        {
+            *ty = concrete.clone();
+            return;
+        }
+        visit_mut::visit_type_mut(self, ty);
+    }
 
-            #generate_table_fn
+    fn visit_type_path_mut(&mut self, ty: &mut syn::TypePath) {
+        self.qualify(&mut ty.qself, &mut ty.path);
+        visit_mut::visit_type_path_mut(self, ty);
+    }
 
-            pub const #lookup_table_ident: &'static #table_type = &generate_table();
+    fn visit_expr_path_mut(&mut self, expr: &mut syn::ExprPath) {
+        self.qualify(&mut expr.qself, &mut expr.path);
+        visit_mut::visit_expr_path_mut(self, expr);
+    }
+}
 
-            #precalc_fn
+/// Replaces the calls `ident(...)` in `tokens` by `replace(args)`, where
+/// `args` is the parenthesized group of arguments. Method calls and paths
+/// ending in `ident` are left alone.
+fn replace_calls(
+    tokens: proc_macro2::TokenStream,
+    ident: &Ident,
+    replace: &dyn Fn(proc_macro2::Group) -> proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let mut output = proc_macro2::TokenStream::new();
+    let mut tokens = tokens.into_iter().peekable();
+    let mut after_punct = false;
+    while let Some(token) = tokens.next() {
+        let is_punct = matches!(&token, proc_macro2::TokenTree::Punct(punct) if matches!(punct.as_char(), '.' | ':'));
+        match token {
+            proc_macro2::TokenTree::Ident(name) if name == *ident && !after_punct => {
+                match tokens.peek() {
+                    Some(proc_macro2::TokenTree::Group(group))
+                        if group.delimiter() == proc_macro2::Delimiter::Parenthesis =>
+                    {
+                        let group = group.clone();
+                        tokens.next();
+                        let mut args = proc_macro2::Group::new(
+                            group.delimiter(),
+                            replace_calls(group.stream(), ident, replace),
+                        );
+                        args.set_span(group.span());
+                        output.extend(replace(args));
+                    }
+                    _ => output.extend([proc_macro2::TokenTree::Ident(name)]),
+                }
+            }
+            proc_macro2::TokenTree::Group(group) => {
+                let mut replaced = proc_macro2::Group::new(
+                    group.delimiter(),
+                    replace_calls(group.stream(), ident, replace),
+                );
+                replaced.set_span(group.span());
+                output.extend([proc_macro2::TokenTree::Group(replaced)]);
+            }
+            token => output.extend([token]),
         }
+        after_punct = is_punct;
+    }
+    output
+}
 
-        #[allow(unused_imports)]
-        #visibility use #mod_name::#func_ident;
+/// Whether a type is written as an `Option`, e.g. `Option<u8>`.
+fn is_option_type(ty: &syn::Type) -> bool {
+    matches!(ty, syn::Type::Path(path) if path.path.segments.last().is_some_and(|segment| segment.ident == "Option"))
+}
+
+/// Whether an option value is a bare keyword such as `static`.
+fn is_keyword_value(value: &Expr) -> bool {
+    matches!(value, Expr::Verbatim(tokens) if ["static", "const", "match"].contains(&tokens.to_string().as_str()))
+}
+
+/// Puts `cfg`, e.g. `#[cfg(not(debug_assertions))]`, on every item of
+/// `items`.
+fn cfg_items(
+    cfg: &Option<proc_macro2::TokenStream>,
+    items: proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let Some(cfg) = cfg else {
+        return Ok(items);
     };
+    let items = syn::parse2::<syn::File>(items)?.items;
+    Ok(quote! { #(#cfg #items)* })
+}
 
-    expanded.into()
+impl PrecalcClosure {
+    fn into_parts(self) -> (Punctuated<Meta, Token![,]>, ItemFn) {
+        let PrecalcClosure {
+            vis,
+            ident,
+            closure,
+            metas,
+        } = self;
+        let inputs = closure.inputs.iter();
+        let output = closure.output;
+        let body = match *closure.body {
+            Expr::Block(block) => block.block,
+            body => parse_quote! {{ #body }},
+        };
+        let func = parse_quote! {
+            #vis const fn #ident(#(#inputs),*) #output #body
+        };
+        (metas, func)
+    }
+}
+
+/// Default limit on the size of the look-up table, see `max_bytes`.
+const DEFAULT_MAX_BYTES: usize = 16 * 1024 * 1024;
+
+/// Largest table that can be generated with `small = match`.
+const SMALL_MATCH_MAX_ARMS: usize = 64;
+
+/// Where the look-up table is stored, see `storage`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Storage {
+    Const,
+    Static,
+    Heap,
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+enum Options {
+    Fallback,
+    Option,
+    Panic,
+}
+
+impl Options {
+    fn from_path(path: &syn::Path) -> Option<Self> {
+        match path.to_token_stream().to_string().trim() {
+            "option" => Some(Options::Option),
+            "panic" => Some(Options::Panic),
+            "fallback" => Some(Options::Fallback),
+            _ => None,
+        }
+    }
+
+    /// The mode of an entry of `variants(...)` and the suffix of the
+    /// function generated for it: `option` generates `<name>_opt`, and `keep`
+    /// is the `fallback` mode generating `<name>_keep`.
+    fn variant(path: &syn::Path) -> Option<(Self, &'static str)> {
+        match path.to_token_stream().to_string().trim() {
+            "option" => Some((Options::Option, "opt")),
+            "keep" => Some((Options::Fallback, "keep")),
+            "fallback" => Some((Options::Fallback, "fallback")),
+            "panic" => Some((Options::Panic, "panic")),
+            _ => None,
+        }
+    }
+
+    /// Name of the mode, as written in the options.
+    fn name(self) -> &'static str {
+        match self {
+            Options::Fallback => "fallback",
+            Options::Option => "option",
+            Options::Panic => "panic",
+        }
+    }
+}
+
+impl Storage {
+    fn from_value(value: &Expr) -> syn::Result<Self> {
+        match value.to_token_stream().to_string().as_str() {
+            "const" => Ok(Storage::Const),
+            "static" => Ok(Storage::Static),
+            "heap" => Ok(Storage::Heap),
+            _ => Err(syn::Error::new_spanned(
+                value,
+                "storage must be either `const`, `static` or `heap`",
+            )),
+        }
+    }
+
+    /// The item holding the look-up table, and an expression borrowing it.
+    fn table_def(
+        self,
+        ident: &Ident,
+        table_type: &proc_macro2::TokenStream,
+        link_section: &Option<proc_macro2::TokenStream>,
+    ) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+        // The order is part of the public interface, `<name>_unindex`,
+        // `values = ...` and `export` rely on it.
+        let table_doc = quote! {
+            /// The precalculated values, nested in argument order: the first
+            /// argument is the outermost dimension and the last one varies
+            /// fastest (row-major order).
+        };
+        match self {
+            Storage::Const => (
+                quote! {
+                    #table_doc
+                    pub const #ident: &'static #table_type = &generate_table();
+                },
+                quote! { #ident },
+            ),
+            Storage::Static => (
+                quote! {
+                    #table_doc
+                    #link_section
+                    pub static #ident: #table_type = generate_table();
+                },
+                quote! { &#ident },
+            ),
+            Storage::Heap => (
+                quote! {
+                    #table_doc
+                    pub static #ident: std::sync::LazyLock<Box<#table_type>> =
+                        std::sync::LazyLock::new(generate_table);
+                },
+                quote! { &#ident },
+            ),
+        }
+    }
+}
+
+/// Parses the modes of `variants(...)` into `variants`.
+fn parse_variants(
+    list: &syn::MetaList,
+    variants: &mut Vec<(Options, &'static str)>,
+) -> syn::Result<()> {
+    let paths = list
+        .parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+        .map_err(|err| {
+            syn::Error::new(
+                err.span(),
+                "variants must be a list of operating modes, e.g. `variants(option, panic)`",
+            )
+        })?;
+    for path in paths {
+        match Options::variant(&path) {
+            Some(variant) if !variants.contains(&variant) => variants.push(variant),
+            Some(_) => {
+                return Err(syn::Error::new_spanned(
+                    &path,
+                    format!(
+                        "the `{}` variant is given more than once",
+                        path.to_token_stream()
+                    ),
+                ));
+            }
+            None => {
+                return Err(syn::Error::new_spanned(
+                    path,
+                    "unknown operating mode, expected `fallback`, `keep`, `option` or `panic`",
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The values of the arguments for each arm of `small = match`, written as
+/// literals so they serve both as patterns and as arguments.
+fn small_match_arms(
+    small: &syn::Path,
+    args: &[Arg],
+) -> syn::Result<Vec<Vec<proc_macro2::TokenStream>>> {
+    let mut arms = vec![Vec::new()];
+    for arg in args {
+        let Some((start, end)) = arg.literal_range() else {
+            return Err(syn::Error::new_spanned(
+                &arg.range,
+                "`small = match` requires integer ranges with literal bounds, e.g. `0..=3`",
+            ));
+        };
+        // The number of values is checked before listing them, as the
+        // range may be too large to list.
+        let len = match start <= end {
+            true => end.abs_diff(start).saturating_add(1),
+            false => 0,
+        };
+        if (arms.len() as u128).saturating_mul(len) > SMALL_MATCH_MAX_ARMS as u128 {
+            return Err(syn::Error::new_spanned(
+                small,
+                format!("`small = match` supports at most {SMALL_MATCH_MAX_ARMS} entries"),
+            ));
+        }
+        arms = arms
+            .into_iter()
+            .flat_map(|values: Vec<_>| {
+                (start..=end).map(move |value| {
+                    let literal = proc_macro2::Literal::i128_unsuffixed(value.abs());
+                    let sign = (value < 0).then(|| quote! { - });
+                    let mut values = values.clone();
+                    values.push(quote! { #sign #literal });
+                    values
+                })
+            })
+            .collect();
+    }
+    Ok(arms)
+}
+
+/// Reads the value of `index_var` from a table of `bool`s stored as bits.
+fn read_bits(bits: &proc_macro2::TokenStream, index_var: &Ident) -> proc_macro2::TokenStream {
+    quote! { (((#bits)[#index_var / 8] >> (#index_var % 8)) & 1 != 0) }
+}
+
+/// The items packing the `bool`s of the look-up table into `bits_ident`.
+/// The `bool`s stay in a constant, only read at compile time, and the bits
+/// are stored as asked.
+fn bits_def(
+    storage: Storage,
+    bits_ident: &Ident,
+    lookup_table_ident: &Ident,
+    link_section: &Option<proc_macro2::TokenStream>,
+) -> proc_macro2::TokenStream {
+    let bits_doc = quote! {
+        /// The look-up table packed as bits, the value of index `i` is
+        /// bit `i % 8` of byte `i / 8`.
+    };
+    let bits_item = match storage {
+        Storage::Static => quote! {
+            #bits_doc
+            #link_section
+            pub static #bits_ident: [u8; BITS_LEN] = generate_bits();
+        },
+        _ => quote! {
+            #bits_doc
+            pub const #bits_ident: &'static [u8; BITS_LEN] = &generate_bits();
+        },
+    };
+    quote! {
+        const BITS_LEN: usize = TABLE_LEN.div_ceil(8);
+
+        const fn generate_bits() -> [u8; BITS_LEN] {
+            let table = #lookup_table_ident;
+            let mut bits = [0u8; BITS_LEN];
+            let mut i = 0;
+            while i < TABLE_LEN {
+                if table[i] {
+                    bits[i / 8] |= 1 << (i % 8);
+                }
+                i += 1;
+            }
+            bits
+        }
+
+        #bits_item
+    }
+}
+
+/// With `release_only`, a value computed by `debug` in debug builds and read
+/// from the table by `release` in release builds.
+fn release_only_value(
+    debug: proc_macro2::TokenStream,
+    release: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    quote! {{
+        #[cfg(debug_assertions)]
+        let value = #debug;
+        #[cfg(not(debug_assertions))]
+        let value = #release;
+        value
+    }}
+}
+
+/// The signature of the precalculated function, shared by the generated
+/// items.
+struct Signature<'a> {
+    ident: &'a Ident,
+    /// The original function, called for inputs out of the table.
+    original: &'a Ident,
+    args: &'a [Arg],
+    params: &'a [proc_macro2::TokenStream],
+    return_ty: &'a syn::Type,
+    generics: &'a syn::Generics,
+    where_clause: Option<&'a syn::WhereClause>,
+}
+
+impl Signature<'_> {
+    fn arg_idents(&self) -> impl Iterator<Item = &Ident> + Clone {
+        self.args.iter().map(|arg| &arg.ident)
+    }
+}
+
+/// With `packed`, an array return type `[T; N]` is also flattened, the
+/// table is then viewed as a single `[T]` holding `N` values per entry.
+/// Returns `<NAME>_PACKED` and `<name>_packed`, and their names.
+fn packed_defs(
+    packed: &syn::Path,
+    sig: &Signature,
+    table_ref: &proc_macro2::TokenStream,
+    item: proc_macro2::TokenStream,
+    index_of_ident: &Ident,
+) -> syn::Result<(proc_macro2::TokenStream, [Ident; 2])> {
+    let syn::Type::Array(array) = sig.return_ty else {
+        return Err(syn::Error::new_spanned(
+            packed,
+            "packed requires an array or `bool` return type, e.g. `[u8; 4]`",
+        ));
+    };
+    let elem = &array.elem;
+    let entry_len = &array.len;
+    let func_ident = sig.ident;
+    let packed_table_ident = format_ident!("{}_PACKED", func_ident.to_string().to_uppercase());
+    let packed_fn_ident = format_ident!("{func_ident}_packed");
+    let flattens = sig.args.iter().map(|_| quote! { .as_flattened() });
+    let params = sig.params;
+    let func_args = sig.arg_idents();
+    Ok((
+        quote! {
+            /// The look-up table as a single slice, holding the values
+            /// of each entry one after the other in row-major order.
+            pub #item #packed_table_ident: &[#elem] = (#table_ref) #(#flattens)*;
+
+            /// Returns the values stored for the arguments as a slice of
+            /// the packed table, [None] if any is out of range.
+            pub const fn #packed_fn_ident(#(#params),*) -> Option<&'static [#elem]> {
+                let Some(flat) = #index_of_ident(#(#func_args),*) else {
+                    return None;
+                };
+                let (_, entry) = #packed_table_ident.split_at(flat * (#entry_len));
+                let (entry, _) = entry.split_at(#entry_len);
+                Some(entry)
+            }
+        },
+        [packed_table_ident, packed_fn_ident],
+    ))
+}
+
+/// Runs `body` for every entry of the look-up table, with the index of each
+/// argument in its `<arg>_idx` variable.
+fn for_each_entry(args: &[Arg], body: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    args.iter().rev().fold(body, |body, arg| {
+        let loop_var = arg.index_var();
+        let size_ident = arg.size_ident();
+        quote! {
+            let mut #loop_var: usize = 0;
+            while #loop_var < #size_ident {
+                #body
+                #loop_var += 1;
+            }
+        }
+    })
+}
+
+/// The value stored at the indices of the arguments in `table`.
+fn table_entry(args: &[Arg]) -> proc_macro2::TokenStream {
+    args.iter().fold(quote! { table }, |acc, arg| {
+        let index_var = arg.index_var();
+        quote! { #acc[#index_var] }
+    })
+}
+
+/// With `value_stats`, a second pass over the finished table finds the
+/// smallest and largest precalculated values. Returns their constants and
+/// names.
+fn value_stats_defs(
+    sig: &Signature,
+    table_ref: &proc_macro2::TokenStream,
+) -> (proc_macro2::TokenStream, [Ident; 2]) {
+    let func_upper = sig.ident.to_string().to_uppercase();
+    let min_ident = format_ident!("{func_upper}_VALUE_MIN");
+    let max_ident = format_ident!("{func_upper}_VALUE_MAX");
+    let return_ty = sig.return_ty;
+    let first = sig
+        .args
+        .iter()
+        .fold(quote! { table }, |acc, _| quote! { #acc[0] });
+    let value = table_entry(sig.args);
+    let loops = for_each_entry(
+        sig.args,
+        quote! {
+            let value = #value;
+            if value < min {
+                min = value;
+            }
+            if value > max {
+                max = value;
+            }
+        },
+    );
+    (
+        quote! {
+            const fn value_stats() -> (#return_ty, #return_ty) {
+                let table = #table_ref;
+                let mut min = #first;
+                let mut max = min;
+                #loops
+                (min, max)
+            }
+            /// Smallest value in the look-up table.
+            pub const #min_ident: #return_ty = value_stats().0;
+            /// Largest value in the look-up table.
+            pub const #max_ident: #return_ty = value_stats().1;
+        },
+        [min_ident, max_ident],
+    )
+}
+
+/// With `store = f16`, a second pass compares the stored `f16` values to the
+/// original function, recording the largest quantization error. Returns its
+/// constant and name.
+fn store_error_def(
+    sig: &Signature,
+    table_ref: &proc_macro2::TokenStream,
+) -> (proc_macro2::TokenStream, Ident) {
+    let ident = format_ident!("{}_STORE_ERROR", sig.ident.to_string().to_uppercase());
+    let stored = table_entry(sig.args);
+    let value_calcs = sig.args.iter().map(|arg| {
+        let ident = &arg.ident;
+        let value = arg.value(&arg.index_var());
+        quote! { let #ident = #value; }
+    });
+    let original = sig.original;
+    let func_args = sig.arg_idents();
+    let loops = for_each_entry(
+        sig.args,
+        quote! {
+            #(#value_calcs)*
+            let error = (#original(#(#func_args),*) - #stored.to_f32_const()).abs();
+            if error > max {
+                max = error;
+            }
+        },
+    );
+    (
+        quote! {
+            /// Largest difference between a value of the original function
+            /// and the `f16` stored for it in the look-up table.
+            pub const #ident: f32 = {
+                let table = #table_ref;
+                let mut max: f32 = 0.0;
+                #loops
+                max
+            };
+        },
+        ident,
+    )
+}
+
+/// With `jagged`, the rows are copied into `JAGGED_VALUES` once the table of
+/// offsets is built, calling the original function for each input again.
+fn jagged_defs(
+    sig: &Signature,
+    elem: &syn::Type,
+    table_ref: &proc_macro2::TokenStream,
+    unindex_ident: &Ident,
+) -> proc_macro2::TokenStream {
+    let flattens = sig
+        .args
+        .iter()
+        .skip(1)
+        .map(|_| quote! { .as_flattened() })
+        .collect::<Vec<_>>();
+    let original = sig.original;
+    let func_args = sig.arg_idents().collect::<Vec<_>>();
+    quote! {
+        const JAGGED_LEN: usize = {
+            let entries = (#table_ref) #(#flattens)*;
+            entries[entries.len() - 1].1 as usize
+        };
+
+        const fn jagged_values() -> [#elem; JAGGED_LEN] {
+            let mut values = [<#elem as recuerdame::PrecalcConst>::DEFAULT; JAGGED_LEN];
+            let entries = (#table_ref) #(#flattens)*;
+            let mut flat = 0;
+            while flat < TABLE_LEN {
+                let (#(#func_args,)*) = #unindex_ident(flat);
+                let row = #original(#(#func_args),*);
+                let (start, _) = entries[flat];
+                let mut i = 0;
+                while i < row.len() {
+                    values[start as usize + i] = row[i];
+                    i += 1;
+                }
+                flat += 1;
+            }
+            values
+        }
+
+        /// The rows of every input, back to back.
+        pub static JAGGED_VALUES: [#elem; JAGGED_LEN] = jagged_values();
+
+        const fn jagged_row((start, end): (u32, u32)) -> &'static [#elem] {
+            let (_, row) = JAGGED_VALUES.split_at(start as usize);
+            let (row, _) = row.split_at((end - start) as usize);
+            row
+        }
+    }
+}
+
+/// Generates the functions looking up the table in an operating mode: the
+/// precalculated function itself, its `variants(...)`, `<name>_nearest` and
+/// `<name>_rt`.
+struct Lookup<'a> {
+    sig: &'a Signature<'a>,
+    inline: &'a proc_macro2::TokenStream,
+    /// The return type and the wrapping of the value in `option` mode.
+    option_return_ty: &'a proc_macro2::TokenStream,
+    option_wrap: &'a Option<proc_macro2::TokenStream>,
+    clamps: &'a [proc_macro2::TokenStream],
+    range_asserts: &'a [proc_macro2::TokenStream],
+    bounds_check: &'a proc_macro2::TokenStream,
+    record_miss: &'a Option<proc_macro2::TokenStream>,
+    /// The arms of `small = match`, read instead of the table.
+    match_arms: Option<&'a [Vec<proc_macro2::TokenStream>]>,
+    /// Reads the table at the indices of the arguments.
+    table_access: &'a proc_macro2::TokenStream,
+    release_only: bool,
+}
+
+impl Lookup<'_> {
+    /// With `assume_in_range`, out of range inputs panic in debug builds and
+    /// are undefined behavior in release builds.
+    fn assumed_in_range(&self) -> proc_macro2::TokenStream {
+        let range_asserts = self.range_asserts;
+        quote! {{
+            if cfg!(debug_assertions) {
+                #(#range_asserts)*
+            }
+            // SAFETY: callers of an `assume_in_range` function guarantee their
+            // inputs are in range.
+            unsafe { core::hint::unreachable_unchecked() }
+        }}
+    }
+
+    fn function(
+        &self,
+        mode: Options,
+        ident: &Ident,
+        index_calcs: &[proc_macro2::TokenStream],
+        assume: bool,
+        constness: &Option<proc_macro2::TokenStream>,
+    ) -> proc_macro2::TokenStream {
+        let (return_ty, body) = match self.match_arms {
+            Some(arms) => self.match_body(arms, mode, assume),
+            None => self.table_body(mode, index_calcs, assume),
+        };
+        let Signature {
+            params,
+            generics,
+            where_clause,
+            ..
+        } = self.sig;
+        let inline = self.inline;
+        let clamps = self.clamps;
+        let track_caller = (mode == Options::Panic).then(|| quote! { #[track_caller] });
+        quote! {
+            #inline
+            #track_caller
+            pub #constness fn #ident #generics (#(#params),*) -> #return_ty #where_clause {
+                #(#clamps)*
+                #body
+            }
+        }
+    }
+
+    /// Matches the inputs against every precalculated value.
+    fn match_body(
+        &self,
+        arms: &[Vec<proc_macro2::TokenStream>],
+        mode: Options,
+        assume: bool,
+    ) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+        let Signature {
+            original,
+            return_ty,
+            ..
+        } = self.sig;
+        let args = self.sig.arg_idents();
+        let range_asserts = self.range_asserts;
+        let record_miss = self.record_miss;
+        let (out_of_range, return_ty, wrap) = match mode {
+            _ if assume => (self.assumed_in_range(), quote! { #return_ty }, None),
+            Options::Panic => (
+                quote! {{
+                    #(#range_asserts)*
+                    unreachable!()
+                }},
+                quote! { #return_ty },
+                None,
+            ),
+            Options::Fallback => (
+                quote! {{
+                    #record_miss
+                    #original(#(#args),*)
+                }},
+                quote! { #return_ty },
+                None,
+            ),
+            Options::Option => (
+                quote! {{
+                    #record_miss
+                    None
+                }},
+                self.option_return_ty.clone(),
+                self.option_wrap.clone(),
+            ),
+        };
+        let arms = arms.iter().map(|values| {
+            quote! { (#(#values,)*) => #wrap(const { #original(#(#values),*) }), }
+        });
+        let args = self.sig.arg_idents();
+        (
+            return_ty,
+            quote! {
+                match (#(#args,)*) {
+                    #(#arms)*
+                    _ => #out_of_range,
+                }
+            },
+        )
+    }
+
+    /// Checks the inputs as the mode requires and reads the table.
+    fn table_body(
+        &self,
+        mode: Options,
+        index_calcs: &[proc_macro2::TokenStream],
+        assume: bool,
+    ) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+        let Signature {
+            original,
+            return_ty,
+            ..
+        } = self.sig;
+        let args = self.sig.arg_idents();
+        let table_access = self.table_access;
+        let mut table_access = quote! {
+            #(#index_calcs)*
+            #table_access
+        };
+        if self.release_only {
+            let args = args.clone();
+            table_access =
+                release_only_value(quote! { #original(#(#args),*) }, quote! {{ #table_access }});
+        }
+        let bounds_check = self.bounds_check;
+        let range_asserts = self.range_asserts;
+        let record_miss = self.record_miss;
+        let (mode_check, return_ty, table_access) = match mode {
+            _ if assume => {
+                let assumed_in_range = self.assumed_in_range();
+                (
+                    quote! {
+                        if !(#bounds_check) #assumed_in_range
+                    },
+                    quote! { #return_ty },
+                    table_access,
+                )
+            }
+            Options::Panic => (
+                quote! { #(#range_asserts)* },
+                quote! { #return_ty },
+                table_access,
+            ),
+            Options::Fallback => (
+                quote! {
+                    if !(#bounds_check) {
+                        #record_miss
+                        return #original(#(#args),*);
+                    }
+                },
+                quote! { #return_ty },
+                table_access,
+            ),
+            // Wrap the return type and the table access in an Option
+            Options::Option => {
+                let option_wrap = self.option_wrap;
+                (
+                    quote! {
+                        if !(#bounds_check) {
+                            #record_miss
+                            return None;
+                        }
+                    },
+                    self.option_return_ty.clone(),
+                    quote! { #option_wrap({ #table_access }) },
+                )
+            }
+        };
+        (
+            return_ty,
+            quote! {
+                #mode_check
+                #table_access
+            },
+        )
+    }
+
+    /// The functions of `variants(...)`, sharing the table in other modes,
+    /// and their names.
+    fn variants(
+        &self,
+        variants: &[(Options, &str)],
+        index_calcs: &[proc_macro2::TokenStream],
+        constness: &Option<proc_macro2::TokenStream>,
+    ) -> (Vec<Ident>, Vec<proc_macro2::TokenStream>) {
+        variants
+            .iter()
+            .map(|(variant, suffix)| {
+                let ident = format_ident!("{}_{suffix}", self.sig.ident);
+                let def = self.function(*variant, &ident, index_calcs, false, constness);
+                (ident, def)
+            })
+            .unzip()
+    }
+}
+
+/// The options given to `#[precalculate(...)]`, and the range of each
+/// argument.
+struct Config {
+    mode: Options,
+    self_value: Option<syn::MetaNameValue>,
+    variants: Vec<(Options, &'static str)>,
+    export: bool,
+    build_table: bool,
+    flat_table: bool,
+    flatten_option: Option<syn::Path>,
+    cfg: Option<Expr>,
+    count_misses: Option<syn::Path>,
+    assume_in_range: Option<syn::Path>,
+    rt: bool,
+    label: Option<syn::LitStr>,
+    recurrence: Option<syn::MetaList>,
+    packed: Option<syn::Path>,
+    jagged: Option<syn::Path>,
+    opaque_table: bool,
+    expect_hash: Option<(syn::Path, u64)>,
+    release_only: Option<syn::Path>,
+    value_stats: Option<syn::Path>,
+    check_order: Vec<Ident>,
+    fill: Option<Expr>,
+    storage: Storage,
+    store_f16: Option<syn::MetaNameValue>,
+    link_section: Option<syn::LitStr>,
+    c_abi: Option<(Ident, Expr)>,
+    ranges_const: Option<Expr>,
+    small_match: Option<syn::Path>,
+    max_bytes: Option<Expr>,
+    inline: proc_macro2::TokenStream,
+    values: Option<Expr>,
+    name: Option<Ident>,
+    transforms: Vec<(Ident, Transform)>,
+    defaults: Vec<Punctuated<syn::MetaNameValue, Token![,]>>,
+    clamped: Vec<String>,
+    markers: Vec<String>,
+    /// The range of each argument and its step, by argument name.
+    range_map: HashMap<String, (proc_macro2::TokenStream, Option<Expr>)>,
+}
+
+impl Config {
+    fn parse(metas: Punctuated<Meta, Token![,]>, func: &ItemFn) -> syn::Result<Self> {
+        let mut mode = Vec::new();
+        let mut self_value = None;
+        let mut variants = Vec::new();
+        let mut export = false;
+        let mut build_table = false;
+        let mut flat_table = false;
+        let mut flatten_option = None;
+        let mut cfg = None;
+        let mut count_misses = None;
+        let mut basic = None;
+        let mut assume_in_range = None;
+        let mut rt = false;
+        let mut label = None;
+        let mut recurrence = None;
+        let mut packed = None;
+        let mut jagged = None;
+        let mut opaque_table = false;
+        let mut expect_hash = None;
+        let mut release_only = None;
+        let mut value_stats = None;
+        let mut check_order = Vec::new();
+        let mut fill = None;
+        let mut storage = Storage::Const;
+        let mut store_f16 = None;
+        let mut link_section = None;
+        let mut c_abi = None;
+        let mut ranges_const = None;
+        let mut small_match = None;
+        let mut max_bytes = None;
+        let mut inline = quote! { #[inline] };
+        let mut values = None;
+        let mut name = None;
+        let mut transforms = Vec::<(Ident, Transform)>::new();
+        let mut defaults = Vec::<Punctuated<syn::MetaNameValue, Token![,]>>::new();
+        let mut clamped = Vec::<String>::new();
+        let mut markers = Vec::<String>::new();
+        let mut range_map = HashMap::<String, (proc_macro2::TokenStream, Option<Expr>)>::new();
+        let arg_names = func
+            .sig
+            .inputs
+            .iter()
+            .filter_map(|arg| match arg {
+                FnArg::Typed(pat_type) => match &*pat_type.pat {
+                    Pat::Ident(pat_ident) => Some(pat_ident.ident.to_string()),
+                    _ => None,
+                },
+                FnArg::Receiver(_) => None,
+            })
+            .collect::<Vec<_>>();
+        for meta in metas {
+            match meta {
+                Meta::NameValue(mnv) if mnv.path.is_ident("check_order") => {
+                    let Expr::Array(array) = mnv.value else {
+                        return Err(syn::Error::new_spanned(
+                            mnv.value,
+                            "check_order must be a list of arguments, e.g. `check_order = [b, a]`",
+                        ));
+                    };
+                    for elem in array.elems {
+                        match elem {
+                            Expr::Path(path) if path.path.get_ident().is_some() => {
+                                check_order.push(path.path.get_ident().unwrap().clone())
+                            }
+                            elem => {
+                                return Err(syn::Error::new_spanned(
+                                    elem,
+                                    "check_order entries must be argument names",
+                                ));
+                            }
+                        }
+                    }
+                }
+                Meta::NameValue(mnv) if mnv.path.is_ident("fill") => fill = Some(mnv.value),
+                Meta::NameValue(mnv) if mnv.path.is_ident("cfg") => cfg = Some(mnv.value),
+                Meta::NameValue(mnv) if mnv.path.is_ident("expect_hash") => {
+                    let error = |value: &dyn ToTokens| {
+                        syn::Error::new_spanned(
+                            value,
+                            "expect_hash must be a 64-bit hexadecimal string, e.g. `expect_hash = \"0x5c4a2f1e8d3b7a60\"`",
+                        )
+                    };
+                    let Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(hash),
+                        ..
+                    }) = &mnv.value
+                    else {
+                        return Err(error(&mnv.value));
+                    };
+                    let value = hash.value();
+                    let digits = value.strip_prefix("0x").unwrap_or(&value);
+                    let hash = u64::from_str_radix(digits, 16).map_err(|_| error(hash))?;
+                    expect_hash = Some((mnv.path, hash));
+                }
+                Meta::NameValue(mnv) if mnv.path.is_ident("label") => match mnv.value {
+                    Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(text),
+                        ..
+                    }) => label = Some(text),
+                    value => {
+                        return Err(syn::Error::new_spanned(
+                            value,
+                            "label must be a string literal, e.g. `label = \"sine LUT v2\"`",
+                        ));
+                    }
+                },
+                Meta::NameValue(mnv) if mnv.path.is_ident("values") => values = Some(mnv.value),
+                Meta::NameValue(mnv) if mnv.path.is_ident("name") => match &mnv.value {
+                    Expr::Path(path) if path.path.get_ident().is_some() => {
+                        name = path.path.get_ident().cloned()
+                    }
+                    value => {
+                        return Err(syn::Error::new_spanned(
+                            value,
+                            "name must be an identifier, e.g. `name = add_small`",
+                        ));
+                    }
+                },
+                Meta::NameValue(mnv) if mnv.path.is_ident("small") => {
+                    if mnv.value.to_token_stream().to_string() != "match" {
+                        return Err(syn::Error::new_spanned(
+                            mnv.value,
+                            "small only supports `small = match`",
+                        ));
+                    }
+                    small_match = Some(mnv.path);
+                }
+                Meta::NameValue(mnv) if mnv.path.is_ident("max_bytes") => {
+                    max_bytes = Some(mnv.value)
+                }
+                Meta::NameValue(mnv) if mnv.path.is_ident("self_value") => self_value = Some(mnv),
+                Meta::NameValue(mnv) if mnv.path.is_ident("inline") => {
+                    inline = match mnv.value.to_token_stream().to_string().as_str() {
+                        "never" => quote! { #[inline(never)] },
+                        "always" => quote! { #[inline(always)] },
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                mnv.value,
+                                "inline must be either `never` or `always`",
+                            ));
+                        }
+                    }
+                }
+                Meta::NameValue(mnv) if mnv.path.is_ident("ranges_const") => {
+                    ranges_const = Some(mnv.value)
+                }
+                Meta::NameValue(mnv) if mnv.path.is_ident("store") => {
+                    if mnv.value.to_token_stream().to_string() != "f16" {
+                        return Err(syn::Error::new_spanned(
+                            mnv.value,
+                            "store only supports `f16`, e.g. `store = f16`",
+                        ));
+                    }
+                    store_f16 = Some(mnv);
+                }
+                Meta::NameValue(mnv) if mnv.path.is_ident("storage") => {
+                    storage = Storage::from_value(&mnv.value)?
+                }
+                Meta::NameValue(mnv) if mnv.path.is_ident("link_section") => match mnv.value {
+                    Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(section),
+                        ..
+                    }) => link_section = Some(section),
+                    value => {
+                        return Err(syn::Error::new_spanned(
+                            value,
+                            "link_section must be a string literal, e.g. `link_section = \".lookup\"`",
+                        ));
+                    }
+                },
+                Meta::NameValue(mnv) if mnv.path.is_ident("c_abi") => match &mnv.value {
+                    Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(symbol),
+                        ..
+                    }) => c_abi = Some((symbol.parse::<Ident>()?, mnv.value)),
+                    value => {
+                        return Err(syn::Error::new_spanned(
+                            value,
+                            "c_abi must be a string literal, e.g. `c_abi = \"add_lookup\"`",
+                        ));
+                    }
+                },
+                Meta::NameValue(mnv) => {
+                    let Some(ident) = mnv.path.get_ident() else {
+                        return Err(syn::Error::new_spanned(
+                            &mnv.path,
+                            "expected an argument name or an option",
+                        ));
+                    };
+                    let key = ident.clone();
+                    let ident = ident.to_string();
+                    // A misspelled option would otherwise be read as the range of
+                    // an argument that doesn't exist.
+                    if !arg_names.contains(&ident) {
+                        let candidates = NAME_VALUE_OPTIONS
+                            .into_iter()
+                            .chain(arg_names.iter().map(String::as_str));
+                        return Err(unknown_option("option or argument", &mnv.path, candidates));
+                    }
+                    let (value, clamp) = match mnv.value {
+                        Expr::Verbatim(tokens) => {
+                            let SteppedRange { range, step } = syn::parse2(tokens)?;
+                            let (range, clamp) = clamp_range(range)?;
+                            ((start_count_range(range)?, Some(step)), clamp)
+                        }
+                        value => match marker_value(&value)? {
+                            Some(marker) => {
+                                markers.push(ident.clone());
+                                ((marker.into_token_stream(), None), false)
+                            }
+                            None => {
+                                let (value, clamp) = clamp_range(value)?;
+                                ((start_count_range(value)?, None), clamp)
+                            }
+                        },
+                    };
+                    if clamp {
+                        clamped.push(ident.clone());
+                    }
+                    if range_map.insert(ident.clone(), value).is_some() {
+                        return Err(syn::Error::new_spanned(
+                            key,
+                            format!("the range of `{ident}` is given more than once"),
+                        ));
+                    }
+                }
+                Meta::Path(opt) if opt.is_ident("export") => export = true,
+                Meta::Path(opt) if opt.is_ident("opaque_table") => opaque_table = true,
+                Meta::Path(opt) if opt.is_ident("build_table") => build_table = true,
+                Meta::Path(opt) if opt.is_ident("flat_table") => flat_table = true,
+                Meta::Path(opt) if opt.is_ident("flatten_option") => flatten_option = Some(opt),
+                Meta::Path(opt) if opt.is_ident("count_misses") => count_misses = Some(opt),
+                Meta::Path(opt) if opt.is_ident("packed") => packed = Some(opt),
+                Meta::Path(opt) if opt.is_ident("jagged") => jagged = Some(opt),
+                Meta::Path(opt) if opt.is_ident("release_only") => release_only = Some(opt),
+                Meta::Path(opt) if opt.is_ident("value_stats") => value_stats = Some(opt),
+                Meta::Path(opt) if opt.is_ident("basic") => basic = Some(opt),
+                Meta::Path(opt) if opt.is_ident("assume_in_range") => assume_in_range = Some(opt),
+                Meta::Path(opt) if opt.is_ident("rt") => rt = true,
+                Meta::Path(opt) => match Options::from_path(&opt) {
+                    Some(mode_opt) => mode.push((mode_opt, opt)),
+                    None => return Err(unknown_option("option", &opt, FLAG_OPTIONS)),
+                },
+                Meta::List(list) if list.path.is_ident("transform") => {
+                    let entries = list.parse_args_with(
+                        Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated,
+                    )?;
+                    for entry in entries {
+                        let error = || {
+                            syn::Error::new_spanned(
+                                &entry,
+                                "transform expects `arg = (to_index, from_index)`",
+                            )
+                        };
+                        let ident = entry.path.get_ident().ok_or_else(error)?.clone();
+                        let Expr::Tuple(tuple) = &entry.value else {
+                            return Err(error());
+                        };
+                        let paths = tuple
+                            .elems
+                            .iter()
+                            .map(|elem| match elem {
+                                Expr::Path(path) => Ok(path.path.clone()),
+                                _ => Err(error()),
+                            })
+                            .collect::<syn::Result<Vec<_>>>()?;
+                        let [to_index, from_index] =
+                            <[_; 2]>::try_from(paths).map_err(|_| error())?;
+                        if transforms.iter().any(|(other, _)| other == &ident) {
+                            return Err(syn::Error::new_spanned(
+                                ident,
+                                "argument has more than one transform",
+                            ));
+                        }
+                        transforms.push((
+                            ident,
+                            Transform {
+                                to_index,
+                                from_index,
+                            },
+                        ));
+                    }
+                }
+                Meta::List(list) if list.path.is_ident("recurrence") => recurrence = Some(list),
+                Meta::List(list) if list.path.is_ident("defaults") => {
+                    defaults.push(list.parse_args_with(Punctuated::parse_terminated)?);
+                }
+                Meta::List(list) if list.path.is_ident("variants") => {
+                    parse_variants(&list, &mut variants)?
+                }
+                Meta::List(list) => return Err(unknown_option("option", &list.path, LIST_OPTIONS)),
+            }
+        }
+
+        // `basic` only spells out the default mode.
+        if let (Some(basic), Some((mode, _))) = (&basic, mode.first()) {
+            return Err(syn::Error::new_spanned(
+                basic,
+                format!(
+                    "`basic` is the default `fallback` mode, it can't be combined with `{}`",
+                    mode.name()
+                ),
+            ));
+        }
+        if let (Some(assume_in_range), Some((mode, _))) = (&assume_in_range, mode.first()) {
+            return Err(syn::Error::new_spanned(
+                assume_in_range,
+                format!(
+                    "assume_in_range replaces the bounds check of the mode, it can't be combined with `{}`",
+                    mode.name()
+                ),
+            ));
+        }
+        let mode = match &mode[..] {
+            [] => Options::Fallback,
+            [(mode, _)] => *mode,
+            [(first, _), (second, path), ..] => {
+                return Err(syn::Error::new_spanned(
+                    path,
+                    format!(
+                        "only one operating mode can be given, found `{}` and `{}`, use `variants(...)` for more",
+                        first.name(),
+                        second.name()
+                    ),
+                ));
+            }
+        };
+        Ok(Config {
+            mode,
+            self_value,
+            variants,
+            export,
+            build_table,
+            flat_table,
+            flatten_option,
+            cfg,
+            count_misses,
+            assume_in_range,
+            rt,
+            label,
+            recurrence,
+            packed,
+            jagged,
+            opaque_table,
+            expect_hash,
+            release_only,
+            value_stats,
+            check_order,
+            fill,
+            storage,
+            store_f16,
+            link_section,
+            c_abi,
+            ranges_const,
+            small_match,
+            max_bytes,
+            inline,
+            values,
+            name,
+            transforms,
+            defaults,
+            clamped,
+            markers,
+            range_map,
+        })
+    }
+}
+
+fn expand(
+    metas: Punctuated<Meta, Token![,]>,
+    func: ItemFn,
+) -> syn::Result<proc_macro2::TokenStream> {
+    if func.sig.constness.is_none() {
+        return Err(syn::Error::new_spanned(
+            func.sig.fn_token,
+            "precalculate requires a `const fn`, the look-up table is built at compile time",
+        ));
+    }
+    let (metas, mut func) = instantiate(metas, func)?;
+
+    let all_metas = metas.clone();
+    let Config {
+        mode,
+        self_value,
+        variants,
+        export,
+        build_table,
+        flat_table,
+        flatten_option,
+        cfg,
+        count_misses,
+        assume_in_range,
+        rt,
+        label,
+        recurrence,
+        packed,
+        jagged,
+        opaque_table,
+        expect_hash,
+        release_only,
+        value_stats,
+        mut check_order,
+        fill,
+        storage,
+        store_f16,
+        link_section,
+        c_abi,
+        ranges_const,
+        small_match,
+        max_bytes,
+        inline,
+        values,
+        name,
+        mut transforms,
+        defaults,
+        clamped,
+        markers,
+        range_map,
+    } = Config::parse(metas, &func)?;
+
+    match (func.sig.receiver(), self_value) {
+        (Some(_), Some(self_value)) => {
+            let metas = all_metas
+                .into_iter()
+                .filter(|meta| !meta.path().is_ident("self_value"))
+                .collect();
+            return expand_method(metas, func, self_value.value, name, mode);
+        }
+        (Some(receiver), None) => {
+            return Err(syn::Error::new_spanned(
+                receiver,
+                "methods require `self_value = EXPR`, the constant `self` the table is built with",
+            ));
+        }
+        (None, Some(self_value)) => {
+            return Err(syn::Error::new_spanned(
+                self_value.path,
+                "self_value only applies to methods taking `self`",
+            ));
+        }
+        (None, None) => (),
+    }
+
+    let visibility = func.vis.clone();
+    let self_ident = func.sig.ident.clone();
+    let func_ident = name.unwrap_or_else(|| func.sig.ident.clone());
+    let new_func_ident = format_ident!("_{func_ident}_original");
+    func.vis = Visibility::Public(syn::token::Pub::default());
+    func.sig.ident = new_func_ident.clone();
+    let func_return_type = &func.sig.output;
+    let return_ty = match func_return_type {
+        // Nothing is stored for `()`, the table only records which inputs are
+        // in range, which `option` and `panic` modes can still check.
+        syn::ReturnType::Default => parse_quote! { () },
+        syn::ReturnType::Type(_, ty) => ty.clone(),
+    };
+
+    // Lifetime parameters are kept on the generated functions, but the table
+    // can only store values that don't borrow from them.
+    let lifetimes = func
+        .sig
+        .generics
+        .lifetimes()
+        .map(|param| param.lifetime.ident.to_string())
+        .collect::<Vec<_>>();
+    if let Some(lifetime) = find_lifetimes(return_ty.to_token_stream())
+        .into_iter()
+        .find(|lifetime| lifetimes.contains(&lifetime.to_string()))
+    {
+        return Err(syn::Error::new(
+            lifetime.span(),
+            format!(
+                "the return type borrows the lifetime parameter '{lifetime}, \
+                 precalculated values must be 'static to be stored in the look-up table"
+            ),
+        ));
+    }
+    let (_, _, where_clause) = func.sig.generics.split_for_impl();
+    let generics = &func.sig.generics;
+
+    if func.sig.inputs.is_empty() {
+        return Err(syn::Error::new(
+            func.sig.paren_token.span.join(),
+            "precalculate requires at least one ranged argument, use a `const` for a single value",
+        ));
+    }
+
+    let mut arg_info = Vec::new();
+    // Every argument without a range is reported at once.
+    let mut missing_ranges: Option<syn::Error> = None;
+    for (position, arg) in func.sig.inputs.iter().enumerate() {
+        if let FnArg::Typed(pat_type) = arg
+            && let Pat::Ident(pat_ident) = &*pat_type.pat
+        {
+            let arg_name = pat_ident.ident.to_string();
+            let arg_type = &pat_type.ty;
+            let transform = transforms
+                .iter()
+                .position(|(ident, _)| ident == &pat_ident.ident)
+                .map(|i| transforms.remove(i).1);
+            if let Some((range_expr, step)) = range_map.get(&arg_name) {
+                let mut arg = Arg::new(
+                    pat_ident.ident.clone(),
+                    arg_type.clone(),
+                    range_expr.clone(),
+                    transform,
+                    step.as_ref().map(ToTokens::to_token_stream),
+                );
+                if markers.contains(&arg_name) {
+                    if let Some(transform) = &arg.transform {
+                        return Err(syn::Error::new_spanned(
+                            &transform.to_index,
+                            "a marker argument has a single value, it can't be transformed",
+                        ));
+                    }
+                    arg.kind = ArgKind::Marker;
+                }
+                if let Ok(Expr::Path(path)) = syn::parse2::<Expr>(range_expr.clone())
+                    && path.path.segments.last().unwrap().ident == "VARIANTS"
+                {
+                    if arg.kind != ArgKind::Enum || arg.transform.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            range_expr,
+                            "`VARIANTS` ranges are only supported on fieldless enum arguments without a transform",
+                        ));
+                    }
+                    arg.kind = ArgKind::Variants;
+                }
+                if let Some(step) = step
+                    && (arg.kind != ArgKind::Int || arg.transform.is_some())
+                {
+                    return Err(syn::Error::new_spanned(
+                        step,
+                        "a step (`by`) is only supported on integer arguments without a transform",
+                    ));
+                }
+                arg.clamp = clamped.contains(&arg_name);
+                if arg.clamp && (arg.kind != ArgKind::Int || arg.transform.is_some()) {
+                    return Err(syn::Error::new_spanned(
+                        &arg.range,
+                        "clamp is only supported on integer arguments without a transform",
+                    ));
+                }
+                arg.make_inclusive()?;
+                arg_info.push(arg);
+            } else if let Some(ranges) = &ranges_const {
+                // `ranges_const` holds a `(min, max)` pair per argument, in order.
+                let position = syn::Index::from(position);
+                let bound_ty = match transform {
+                    Some(_) => quote! { usize },
+                    None => quote! { #arg_type },
+                };
+                arg_info.push(Arg::new(
+                    pat_ident.ident.clone(),
+                    arg_type.clone(),
+                    quote! {
+                        (#ranges[#position].0 as #bound_ty)..=(#ranges[#position].1 as #bound_ty)
+                    },
+                    transform,
+                    None,
+                ));
+            } else {
+                let error = syn::Error::new_spanned(
+                    &pat_ident.ident,
+                    format!(
+                        "argument `{arg_name}` does not have a specified range, add e.g. `{arg_name} = 0..=10`"
+                    ),
+                );
+                match &mut missing_ranges {
+                    Some(errors) => errors.combine(error),
+                    None => missing_ranges = Some(error),
+                }
+            }
+        }
+    }
+    if let Some(errors) = missing_ranges {
+        return Err(errors);
+    }
+
+    if let Some((ident, _)) = transforms.first() {
+        return Err(syn::Error::new_spanned(
+            ident,
+            format!("transform refers to unknown argument '{ident}'"),
+        ));
+    }
+    if let [_, second, ..] = arg_info
+        .iter()
+        .filter(|arg| arg.open)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        return Err(syn::Error::new_spanned(
+            &second.range,
+            "only one range may be open, the others size the budget it gets from max_bytes",
+        ));
+    }
+
+    // Arguments listed in `check_order` are bounds checked first, the rest
+    // follow in declaration order.
+    for (i, ident) in check_order.iter().enumerate() {
+        if check_order[..i].contains(ident) {
+            return Err(syn::Error::new_spanned(
+                ident,
+                format!("check_order lists argument '{ident}' more than once"),
+            ));
+        }
+        if !arg_info.iter().any(|arg| &arg.ident == ident) {
+            return Err(syn::Error::new_spanned(
+                ident,
+                format!("check_order refers to unknown argument '{ident}'"),
+            ));
+        }
+    }
+    for arg in &arg_info {
+        if !check_order.contains(&arg.ident) {
+            check_order.push(arg.ident.clone());
+        }
+    }
+
+    // With `recurrence(depends_on = [n - 1, ...])`, the table of a recursive
+    // function is filled in ascending order, and the recursive calls of the
+    // body read the entries already filled instead of recursing.
+    let recurrence_ident = format_ident!("_{func_ident}_recurrence");
+    let recurrence_fn = match recurrence {
+        Some(list) => {
+            let arg = match arg_info.as_slice() {
+                [arg]
+                    if arg.kind == ArgKind::Int
+                        && arg.transform.is_none()
+                        && arg.step.is_none()
+                        && values.is_none()
+                        && storage != Storage::Heap =>
+                {
+                    arg
+                }
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        list,
+                        "recurrence requires a single integer argument without a step, a \
+                         transform, `values` or `storage = heap`",
+                    ));
+                }
+            };
+            let error = |tokens: &dyn ToTokens| {
+                syn::Error::new_spanned(
+                    tokens,
+                    format!(
+                        "recurrence expects `depends_on = [{0} - 1, ...]`, each entry a smaller `{0}`",
+                        arg.ident
+                    ),
+                )
+            };
+            let options = list
+                .parse_args_with(Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated)?;
+            let depends_on = match options.first() {
+                Some(option) if options.len() == 1 && option.path.is_ident("depends_on") => {
+                    &option.value
+                }
+                _ => return Err(error(&list)),
+            };
+            let Expr::Array(array) = depends_on else {
+                return Err(error(depends_on));
+            };
+            for elem in &array.elems {
+                match elem {
+                    Expr::Binary(syn::ExprBinary {
+                        left,
+                        op: syn::BinOp::Sub(_),
+                        right,
+                        ..
+                    }) if matches!(&**left, Expr::Path(path) if path.path.is_ident(&arg.ident))
+                        && literal(right).is_some_and(|offset| offset > 0) => {}
+                    elem => return Err(error(elem)),
+                }
+            }
+
+            // Outside of the table filling, the original function recurses
+            // on itself rather than on the precalculated function.
+            func.block = syn::parse2(replace_calls(
+                func.block.to_token_stream(),
+                &self_ident,
+                &|args| quote! { #new_func_ident #args },
+            ))?;
+            let ty = &arg.ty;
+            let block = replace_calls(func.block.to_token_stream(), &new_func_ident, &|args| {
+                let input = args.stream();
+                quote! {({
+                    let input: #ty = #input;
+                    let index = (input as i128).wrapping_sub(recurrence_first as i128) as usize;
+                    if input >= recurrence_first && index < recurrence_filled.len() {
+                        recurrence_filled[index]
+                    } else {
+                        #new_func_ident(input)
+                    }
+                })}
+            });
+            let mut sig = func.sig.clone();
+            sig.ident = recurrence_ident.clone();
+            sig.inputs.insert(0, parse_quote! { recurrence_first: #ty });
+            sig.inputs
+                .insert(0, parse_quote! { recurrence_filled: &[#return_ty] });
+            Some(quote! {
+                /// The original function, reading its recursive calls from
+                /// the entries of the table filled so far, from the input
+                /// `recurrence_first` on.
+                pub #sig #block
+            })
+        }
+        None => None,
+    };
+
+    // The range expressions are evaluated next to the original function, in
+    // a scope that only contains the user's items, so generated names can
+    // never shadow what they refer to.
+    let user_range_ident = |ident: &Ident| {
+        format_ident!(
+            "_{}_{}_RANGE",
+            func_ident.to_string().to_uppercase(),
+            ident.to_string().to_uppercase()
+        )
+    };
+    let user_step_ident = |ident: &Ident| {
+        format_ident!(
+            "_{}_{}_STEP",
+            func_ident.to_string().to_uppercase(),
+            ident.to_string().to_uppercase()
+        )
+    };
+    let range_defs = arg_info.iter().map(|arg| {
+        let Arg { ident, range, .. } = arg;
+        let range_ty = arg.user_range_ty();
+        let user_range_ident = user_range_ident(ident);
+        let step_def = arg.step.as_ref().map(|step| {
+            let user_step_ident = user_step_ident(ident);
+            quote! { pub const #user_step_ident: i128 = #step; }
+        });
+        // A stepped range is descending with a negative step, it is never
+        // iterated as a `RangeInclusive`.
+        let allow_reversed = arg
+            .step
+            .is_some()
+            .then(|| quote! { #[allow(clippy::reversed_empty_ranges)] });
+        quote! {
+            #allow_reversed
+            pub const #user_range_ident: #range_ty = #range;
+            #step_def
+        }
+    });
+
+    let ranges_const_check = ranges_const.map(|ranges| {
+        let arg_count = func.sig.inputs.len();
+        quote! {
+            const _: () = assert!(
+                #ranges.len() == #arg_count,
+                "ranges_const must have one (min, max) pair per argument"
+            );
+        }
+    });
+
+    // With `jagged`, a function returning `&'static [T]` of varying lengths
+    // stores its rows back to back in one array, the table only holds the
+    // `(start, end)` offsets of each row in it.
+    let jagged_elem = match &jagged {
+        Some(jagged) => {
+            let syn::Type::Reference(syn::TypeReference { elem, .. }) = &*return_ty else {
+                return Err(syn::Error::new_spanned(
+                    jagged,
+                    "jagged requires a `&'static [T]` return type",
+                ));
+            };
+            let syn::Type::Slice(slice) = &**elem else {
+                return Err(syn::Error::new_spanned(
+                    jagged,
+                    "jagged requires a `&'static [T]` return type",
+                ));
+            };
+            if storage == Storage::Heap
+                || values.is_some()
+                || fill.is_some()
+                || value_stats.is_some()
+                || flat_table
+                || recurrence_fn.is_some()
+            {
+                return Err(syn::Error::new_spanned(
+                    jagged,
+                    "jagged can't be combined with `storage = heap`, `values`, `fill`, \
+                     `value_stats`, `flat_table` or `recurrence`",
+                ));
+            }
+            Some(slice.elem.clone())
+        }
+        None => None,
+    };
+    // With `store = f16`, the values of a function returning `f32` are
+    // stored as `half::f16` and widened back to `f32` when read.
+    if let Some(store) = &store_f16 {
+        if !matches!(&*return_ty, syn::Type::Path(ty) if ty.qself.is_none() && ty.path.is_ident("f32"))
+        {
+            return Err(syn::Error::new_spanned(
+                &return_ty,
+                "`store = f16` requires an `f32` return type",
+            ));
+        }
+        if storage == Storage::Heap
+            || values.is_some()
+            || value_stats.is_some()
+            || flat_table
+            || recurrence_fn.is_some()
+        {
+            return Err(syn::Error::new_spanned(
+                store,
+                "`store = f16` can't be combined with `storage = heap`, `values`, \
+                 `value_stats`, `flat_table` or `recurrence`",
+            ));
+        }
+    }
+    let widen = |access: proc_macro2::TokenStream| match store_f16 {
+        Some(_) => quote! { #access.to_f32_const() },
+        None => access,
+    };
+    let stored_ty = match (&jagged_elem, &store_f16) {
+        (Some(_), _) => quote! { (u32, u32) },
+        (None, Some(_)) => quote! { recuerdame::__private::f16 },
+        (None, None) => quote! { #return_ty },
+    };
+
+    let user_max_bytes_ident =
+        format_ident!("_{}_MAX_BYTES", func_ident.to_string().to_uppercase());
+    // A table on the heap is neither embedded in the binary nor evaluated
+    // at compile time, so it is only limited by the size of an array.
+    let max_bytes = max_bytes.unwrap_or_else(|| match storage {
+        Storage::Heap => parse_quote! { usize::MAX },
+        _ => parse_quote! { #DEFAULT_MAX_BYTES },
+    });
+    let max_bytes_def = quote! {
+        pub const #user_max_bytes_ident: usize = #max_bytes;
+    };
+
+    // Every dimension goes through `table_dimension`, so an oversized table
+    // fails with a clear error before its type or contents are evaluated.
+    let table_size_defs = {
+        let len_idents = arg_info.iter().map(Arg::len_ident);
+        quote! {
+            const TABLE_LEN: usize = {
+                let len: usize = 1;
+                #(
+                    let len = match len.checked_mul(#len_idents) {
+                        Some(len) => len,
+                        None => panic!("precalculated table has too many entries"),
+                    };
+                )*
+                len
+            };
+            // No array may exceed isize::MAX bytes, whatever max_bytes allows.
+            const TABLE_BYTES: usize = match TABLE_LEN.checked_mul(core::mem::size_of::<#stored_ty>()) {
+                Some(bytes) if bytes <= isize::MAX as usize => bytes,
+                _ => panic!(
+                    "precalculated table is larger than isize::MAX bytes, the limit of any array"
+                ),
+            };
+            const fn table_dimension(len: usize) -> usize {
+                assert!(
+                    TABLE_BYTES <= #user_max_bytes_ident,
+                    "precalculated table is larger than max_bytes (16 MiB by default), reduce the ranges or raise max_bytes"
+                );
+                len
+            }
+        }
+    };
+
+    let const_defs = arg_info.iter().map(|arg| {
+        // An open range gets the entries left by the other dimensions.
+        let other_lens = arg_info
+            .iter()
+            .filter(|other| other.ident != arg.ident)
+            .map(Arg::len_ident);
+        let open_entries = quote! {{
+            let entries = #user_max_bytes_ident
+                / match core::mem::size_of::<#stored_ty>() {
+                    0 => 1,
+                    size => size,
+                };
+            #(let entries = entries / #other_lens;)*
+            entries
+        }};
+        arg.const_defs(
+            &user_range_ident(&arg.ident),
+            &user_step_ident(&arg.ident),
+            open_entries,
+        )
+    });
+
+    let table_type = arg_info.iter().rev().fold(stored_ty.clone(), |inner, arg| {
+        let size_ident = arg.size_ident();
+        quote! { [#inner; #size_ident] }
+    });
+
+    let func_args = arg_info.iter().map(|arg| &arg.ident);
+
+    // Like the ranges, the fill value is evaluated next to the original function.
+    let user_fill_ident = format_ident!("_{}_FILL", func_ident.to_string().to_uppercase());
+    let fill_def = fill.map(|fill| {
+        quote! {
+            pub const #user_fill_ident: #return_ty = #fill;
+        }
+    });
+
+    // Precomputed values, in row-major order, replace the calls to the
+    // original function when building the table.
+    let user_values_ident = format_ident!("_{}_VALUES", func_ident.to_string().to_uppercase());
+    let values_def = values.map(|values| {
+        quote! {
+            pub const #user_values_ident: &[#return_ty] = #values;
+        }
+    });
+    let values_check = values_def.as_ref().map(|_| {
+        quote! {
+            const _: () = assert!(
+                #user_values_ident.len() == TABLE_LEN,
+                "values must hold exactly one value per table entry"
+            );
+        }
+    });
+
+    let (generate_table_fn, build_table_fn) = {
+        let table_init_value = match (&fill_def, &jagged_elem) {
+            (Some(_), _) if store_f16.is_some() => {
+                quote! { recuerdame::__private::f16::from_f32_const(#user_fill_ident) }
+            }
+            (Some(_), _) => quote! { #user_fill_ident },
+            (None, Some(_)) => quote! { (0, 0) },
+            (None, None) => quote! { recuerdame::PrecalcConst::DEFAULT },
+        };
+        let value_calcs = arg_info
+            .iter()
+            .map(|arg| {
+                let ident = &arg.ident;
+                let value = arg.value(&arg.index_var());
+                quote! { let #ident = #value; }
+            })
+            .collect::<Vec<_>>();
+
+        // Fills the dimensions of `args`, the last ones of the table. The
+        // index variables of the dimensions before them must be in scope.
+        let fill_loops = |args: &[Arg], first_flat_index: proc_macro2::TokenStream| {
+            let func_args = func_args.clone();
+            let value = match (&values_def, &recurrence_fn) {
+                (Some(_), _) => quote! { #user_values_ident[flat_index] },
+                (None, None) if jagged_elem.is_some() => quote! {{
+                    #(#value_calcs)*
+                    let row = #new_func_ident(#(#func_args),*);
+                    let start = jagged_end;
+                    jagged_end += row.len();
+                    assert!(
+                        jagged_end <= u32::MAX as usize,
+                        "jagged rows hold more than u32::MAX values"
+                    );
+                    (start as u32, jagged_end as u32)
+                }},
+                (None, Some(_)) => {
+                    let min_ident = arg_info[0].min_ident();
+                    let index_var = arg_info[0].index_var();
+                    quote! {{
+                        #(#value_calcs)*
+                        let (filled, _) = table.split_at(#index_var);
+                        #recurrence_ident(filled, #min_ident, #(#func_args),*)
+                    }}
+                }
+                (None, None) if store_f16.is_some() => quote! {{
+                    #(#value_calcs)*
+                    recuerdame::__private::f16::from_f32_const(#new_func_ident(#(#func_args),*))
+                }},
+                (None, None) => quote! {{
+                    #(#value_calcs)*
+                    #new_func_ident(#(#func_args),*)
+                }},
+            };
+            if args.is_empty() {
+                let flat_index = values_def
+                    .is_some()
+                    .then(|| quote! { let flat_index = #first_flat_index; });
+                return quote! {
+                    #flat_index
+                    #value
+                };
+            }
+            let table_init_expr = args
+                .iter()
+                .rev()
+                .fold(table_init_value.clone(), |inner, arg| {
+                    let size_ident = arg.size_ident();
+                    quote! { [#inner; #size_ident] }
+                });
+            let table_access = table_entry(args);
+            let nested_loops = for_each_entry(
+                args,
+                match values_def {
+                    Some(_) => quote! {
+                        #table_access = #value;
+                        flat_index += 1;
+                    },
+                    None => quote! {
+                        #table_access = #value;
+                    },
+                },
+            );
+            let flat_index = values_def
+                .is_some()
+                .then(|| quote! { let mut flat_index = #first_flat_index; });
+            let jagged_end = jagged_elem
+                .is_some()
+                .then(|| quote! { let mut jagged_end: usize = 0; });
+            quote! {
+                let mut table = #table_init_expr;
+                #flat_index
+                #jagged_end
+                #nested_loops
+                table
+            }
+        };
+
+        let table_body = fill_loops(&arg_info, quote! { 0 });
+        // `build_table` runs the same loops at run time, e.g. from `build.rs`
+        // or a test, free of the limits of const evaluation.
+        let build_table_fn = build_table.then(|| {
+            let build_table_ident = format_ident!("{func_ident}_build_table");
+            (
+                quote! {
+                    /// Builds the look-up table at run time, evaluating the
+                    /// original function for every input.
+                    pub fn #build_table_ident() -> #table_type {
+                        #table_body
+                    }
+                },
+                build_table_ident,
+            )
+        });
+        // A table on the heap is built one row of the first dimension at a
+        // time, so only a row ever goes through the stack.
+        let generate_table_fn = match storage {
+            Storage::Heap => {
+                let (first, rest) = arg_info.split_first().unwrap();
+                let first_index = first.index_var();
+                let row_type = rest.iter().rev().fold(quote! { #return_ty }, |inner, arg| {
+                    let size_ident = arg.size_ident();
+                    quote! { [#inner; #size_ident] }
+                });
+                let first_size = first.size_ident();
+                let row_body =
+                    fill_loops(rest, quote! { #first_index * (TABLE_LEN / #first_size) });
+                quote! {
+                    fn generate_row(#first_index: usize) -> #row_type {
+                        #row_body
+                    }
+                    fn generate_table() -> Box<#table_type> {
+                        let rows = (0..#first_size).map(generate_row).collect::<Box<[_]>>();
+                        match rows.try_into() {
+                            Ok(table) => table,
+                            Err(_) => unreachable!(),
+                        }
+                    }
+                }
+            }
+            _ => quote! {
+                const fn generate_table() -> #table_type {
+                    #table_body
+                }
+            },
+        };
+        (generate_table_fn, build_table_fn)
+    };
+    let (build_table_fn, build_table_ident) = build_table_fn.unzip();
+
+    let mod_name = format_ident!("_mod_precalc_{}", func_ident);
+
+    let lookup_table_ident =
+        format_ident!("LOOKUP_TABLE_{}", func_ident.to_string().to_uppercase());
+
+    let fn_params = arg_info
+        .iter()
+        .map(|Arg { ident, ty, .. }| quote! { #ident: #ty })
+        .collect::<Vec<_>>();
+    let index_calcs = arg_info
+        .iter()
+        .map(|arg| {
+            let index_var = arg.index_var();
+            let index = arg.index();
+            quote! { let #index_var = #index; }
+        })
+        .collect::<Vec<_>>();
+    let index_vars = arg_info.iter().map(Arg::index_var).collect::<Vec<_>>();
+    let index_vars_ty = index_vars.iter().map(|_| quote! { usize });
+
+    let bounds_check_expr = {
+        let per_ident_check = check_order.iter().map(|ident| {
+            let arg = arg_info.iter().find(|arg| &arg.ident == ident).unwrap();
+            arg.in_range()
+        });
+
+        if check_order.is_empty() {
+            quote! { true }
+        } else {
+            quote! { #(#per_ident_check)&&* }
+        }
+    };
+
+    // Whether every input is precalculated, making the out of range branches
+    // dead code (which the compiler already removes).
+    let covers_full_domain_ident = format_ident!(
+        "{}_COVERS_FULL_DOMAIN",
+        func_ident.to_string().to_uppercase()
+    );
+    let covers_full_domain_def = {
+        let per_arg = arg_info.iter().map(Arg::covers_domain);
+        quote! {
+            /// Whether the precalculated ranges contain every value of the argument types.
+            pub const #covers_full_domain_ident: bool = true #(&& #per_arg)*;
+        }
+    };
+
+    let dims_ident = format_ident!("{}_DIMS", func_ident.to_string().to_uppercase());
+    let dims_def = {
+        let size_idents = arg_info.iter().map(Arg::size_ident);
+        quote! {
+            /// Size of each dimension of the look-up table, in argument order.
+            pub const #dims_ident: &[usize] = &[#(#size_idents),*];
+        }
+    };
+
+    let sig = Signature {
+        ident: &func_ident,
+        original: &new_func_ident,
+        args: &arg_info,
+        params: &fn_params,
+        return_ty: &return_ty,
+        generics,
+        where_clause,
+    };
+
+    let mut table_access = widen(index_vars.iter().fold(
+        quote! { #lookup_table_ident },
+        |acc, index_var| quote! { #acc[#index_var] },
+    ));
+    if jagged_elem.is_some() {
+        table_access = quote! { jagged_row(#table_access) };
+    }
+    // A `bool` function of a single argument stores its values as bits, one
+    // byte per eight entries. It is the default over a full byte, e.g.
+    // `b = 0..=255`, and is asked for with `packed` over any other range.
+    let returns_bool = matches!(&*return_ty, syn::Type::Path(ty) if ty.qself.is_none() && ty.path.is_ident("bool"));
+    let bits_ident = (arg_info.len() == 1
+        && returns_bool
+        && storage != Storage::Heap
+        && (arg_info[0].covers_byte() || packed.is_some()))
+    .then(|| format_ident!("{lookup_table_ident}_BITS"));
+    let bits_ref = bits_ident.as_ref().map(|bits_ident| match storage {
+        Storage::Static => quote! { &#bits_ident },
+        _ => quote! { #bits_ident },
+    });
+    if let Some(bits_ref) = &bits_ref {
+        table_access = read_bits(bits_ref, &index_vars[0]);
+    }
+
+    // Panic mode reports the first out of range argument, in `check_order`.
+    let range_asserts = check_order
+        .iter()
+        .map(|ident| {
+            let arg = arg_info.iter().find(|arg| &arg.ident == ident).unwrap();
+            arg.range_assert()
+        })
+        .collect::<Vec<_>>();
+
+    if let (Storage::Heap, Some(value_stats)) = (storage, &value_stats) {
+        return Err(syn::Error::new_spanned(
+            value_stats,
+            "value_stats can't be combined with `storage = heap`, the table is built at run time",
+        ));
+    }
+    if let (Storage::Heap, Some(small)) = (storage, &small_match) {
+        return Err(syn::Error::new_spanned(
+            small,
+            "`small = match` can't be combined with `storage = heap`, it has no table",
+        ));
+    }
+    if let (Some(release_only), Some(_)) = (&release_only, &small_match) {
+        return Err(syn::Error::new_spanned(
+            release_only,
+            "release_only can't be combined with `small = match`, which has no table",
+        ));
+    }
+    // With `small = match` every input is matched directly against its
+    // precalculated value, which requires ranges with literal bounds.
+    let match_arms = small_match
+        .map(|small| small_match_arms(&small, &arg_info))
+        .transpose()?;
+
+    // The table on the heap is built at run time, functions reading it
+    // can't be `const`.
+    let table_constness = (storage != Storage::Heap).then(|| quote! { const });
+    // Counting misses updates an atomic, so the lookup functions are not
+    // `const` either.
+    let lookup_constness = table_constness.clone().filter(|_| count_misses.is_none());
+    let record_miss = count_misses.as_ref().map(|_| quote! { record_miss(); });
+    // With `flatten_option`, option mode returns the `Option` of the function
+    // as is instead of nesting it, out of range inputs are `None` too.
+    if let Some(flatten_option) = &flatten_option
+        && !is_option_type(&return_ty)
+    {
+        return Err(syn::Error::new_spanned(
+            flatten_option,
+            "flatten_option requires the function to return an `Option`",
+        ));
+    }
+    let (option_return_ty, option_wrap) = match flatten_option {
+        Some(_) => (quote! { #return_ty }, None),
+        None => (quote! { Option<#return_ty> }, Some(quote! { Some })),
+    };
+
+    // Clamped arguments are moved into their range before anything else, so
+    // they are never out of range.
+    let clamps = arg_info
+        .iter()
+        .filter_map(Arg::clamp_input)
+        .collect::<Vec<_>>();
+    let lookup = Lookup {
+        sig: &sig,
+        inline: &inline,
+        option_return_ty: &option_return_ty,
+        option_wrap: &option_wrap,
+        clamps: &clamps,
+        range_asserts: &range_asserts,
+        bounds_check: &bounds_check_expr,
+        record_miss: &record_miss,
+        match_arms: match_arms.as_deref(),
+        table_access: &table_access,
+        release_only: release_only.is_some(),
+    };
+
+    let (variant_idents, variant_fns) = lookup.variants(&variants, &index_calcs, &lookup_constness);
+
+    // With a stepped argument, `<name>_nearest` rounds inputs between two
+    // precalculated values to the closest one instead of the one below.
+    let nearest_ident = arg_info
+        .iter()
+        .any(|arg| arg.step.is_some())
+        .then(|| format_ident!("{func_ident}_nearest"));
+    let nearest_fn = nearest_ident.as_ref().map(|ident| {
+        let index_calcs = arg_info
+            .iter()
+            .map(|arg| {
+                let index_var = arg.index_var();
+                let index = arg.nearest_index();
+                quote! { let #index_var = #index; }
+            })
+            .collect::<Vec<_>>();
+        lookup.function(
+            mode,
+            ident,
+            &index_calcs,
+            assume_in_range.is_some(),
+            &lookup_constness,
+        )
+    });
+    // With `rt`, `<name>_rt` is the same lookup as a regular function, for
+    // callers that don't need it to be `const`.
+    let rt_ident = rt.then(|| format_ident!("{func_ident}_rt"));
+    let rt_fn = rt_ident
+        .as_ref()
+        .map(|ident| lookup.function(mode, ident, &index_calcs, assume_in_range.is_some(), &None));
+    let precalc_fn = lookup.function(
+        mode,
+        &func_ident,
+        &index_calcs,
+        assume_in_range.is_some(),
+        &lookup_constness,
+    );
+
+    // Each `defaults(...)` generates a wrapper fixing some arguments, named
+    // after them and their values, e.g. `add_b2` for `defaults(b = 2)`.
+    let mut default_idents = Vec::new();
+    let mut default_fns = Vec::new();
+    for fixed in &defaults {
+        let mut name = func_ident.to_string();
+        for entry in fixed {
+            let Some(ident) = entry.path.get_ident() else {
+                return Err(syn::Error::new_spanned(
+                    &entry.path,
+                    "expected an argument name",
+                ));
+            };
+            if !arg_info.iter().any(|arg| &arg.ident == ident) {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    format!("defaults refers to unknown argument '{ident}'"),
+                ));
+            }
+            name.push_str(&format!("_{ident}{}", default_suffix(&entry.value)?));
+        }
+        let ident = format_ident!("{name}");
+        let params = arg_info
+            .iter()
+            .filter(|arg| !fixed.iter().any(|entry| entry.path.is_ident(&arg.ident)))
+            .map(|Arg { ident, ty, .. }| quote! { #ident: #ty });
+        let call_args = arg_info.iter().map(|arg| {
+            match fixed.iter().find(|entry| entry.path.is_ident(&arg.ident)) {
+                Some(entry) => entry.value.to_token_stream(),
+                None => arg.ident.to_token_stream(),
+            }
+        });
+        let (track_caller, return_ty) = match mode {
+            Options::Panic => (Some(quote! { #[track_caller] }), quote! { #return_ty }),
+            Options::Fallback => (None, quote! { #return_ty }),
+            Options::Option => (None, option_return_ty.clone()),
+        };
+        default_fns.push(quote! {
+            #inline
+            #track_caller
+            pub #lookup_constness fn #ident #generics (#(#params),*) -> #return_ty #where_clause {
+                #func_ident(#(#call_args),*)
+            }
+        });
+        default_idents.push(ident);
+    }
+
+    if let Some(section) = &link_section
+        && storage != Storage::Static
+    {
+        return Err(syn::Error::new_spanned(
+            section,
+            "link_section requires `storage = static`, constants have no address to place",
+        ));
+    }
+    let link_section = link_section.map(|section| quote! { #[unsafe(link_section = #section)] });
+    // Tables stored as bits keep the `bool`s in a constant, only read at
+    // compile time, and store the bits as asked instead.
+    let table_storage = match &bits_ident {
+        Some(_) => Storage::Const,
+        None => storage,
+    };
+    let byte_bits_def = bits_ident
+        .as_ref()
+        .map(|bits_ident| bits_def(storage, bits_ident, &lookup_table_ident, &link_section));
+    let (table_def, table_ref) =
+        table_storage.table_def(&lookup_table_ident, &table_type, &link_section);
+
+    let unindex_ident = format_ident!("{func_ident}_unindex");
+
+    let jagged_defs = jagged_elem
+        .as_ref()
+        .map(|elem| jagged_defs(&sig, elem, &table_ref, &unindex_ident));
+
+    // `expect_hash` reads the table as bytes, which only works for types
+    // without padding or pointers. Anything else fails to evaluate.
+    let table_hash = match expect_hash {
+        Some((path, _)) if storage == Storage::Heap => {
+            return Err(syn::Error::new_spanned(
+                path,
+                "expect_hash can't be combined with `storage = heap`, the table is built at run time",
+            ));
+        }
+        Some((_, expected)) => Some(quote! {
+            const _: () = {
+                let table: &#table_type = #table_ref;
+                // SAFETY: `table` points to a live table of
+                // `size_of::<#table_type>()` initialized bytes. Padding or
+                // pointers in it make constant evaluation fail instead.
+                let bytes = unsafe {
+                    core::slice::from_raw_parts(
+                        (table as *const #table_type).cast::<u8>(),
+                        core::mem::size_of::<#table_type>(),
+                    )
+                };
+                let hash = recuerdame::__private::fnv1a(bytes);
+                if hash != #expected {
+                    recuerdame::__private::hash_mismatch(hash);
+                }
+            };
+        }),
+        None => None,
+    };
+
+    let checked_ident = format_ident!("{func_ident}_checked");
+    let checked_fn = quote! {
+        /// Validates the arguments against their ranges and returns the
+        /// index of each one in the look-up table, [None] if any is out of range.
+        pub const fn #checked_ident(#(#fn_params),*) -> Option<(#(#index_vars_ty,)*)> {
+            if !(#bounds_check_expr) {
+                return None;
+            }
+            #(#index_calcs)*
+            Some((#(#index_vars,)*))
+        }
+    };
+
+    let used_table_ident = format_ident!("{func_ident}_used_table");
+    // With `release_only`, debug builds never read the table.
+    let debug_skips_table = release_only.is_some().then(|| {
+        quote! {
+            if cfg!(debug_assertions) {
+                return false;
+            }
+        }
+    });
+    let used_table_fn = quote! {
+        /// Whether the function reads the value of the arguments from the
+        /// look-up table. Otherwise, it calls the original function in
+        /// `fallback` mode, returns [None] in `option` mode and panics in
+        /// `panic` mode.
+        pub const fn #used_table_ident(#(#fn_params),*) -> bool {
+            #debug_skips_table
+            #(#clamps)*
+            #bounds_check_expr
+        }
+    };
+
+    let by_index_ident = format_ident!("{func_ident}_by_index");
+    let by_index_fn = {
+        let index_params = index_vars
+            .iter()
+            .map(|index_var| quote! { #index_var: usize });
+        // The bits of the last byte past the table are padding, reading them
+        // wouldn't panic.
+        let table_access = match &bits_ref {
+            Some(_) => {
+                let index_var = &index_vars[0];
+                quote! {{
+                    assert!(#index_var < TABLE_LEN, "index is out of the precalculated table");
+                    #table_access
+                }}
+            }
+            None => table_access.clone(),
+        };
+        // With `release_only`, debug builds compute the value at the indices
+        // with the original function.
+        let access = match &release_only {
+            Some(_) => {
+                let value_calcs = arg_info.iter().map(|arg| {
+                    let ident = &arg.ident;
+                    let index_var = arg.index_var();
+                    let size_ident = arg.size_ident();
+                    let value = arg.value(&index_var);
+                    quote! {
+                        assert!(#index_var < #size_ident, "index is out of the precalculated table");
+                        let #ident = #value;
+                    }
+                });
+                let func_args = func_args.clone();
+                release_only_value(
+                    quote! {{
+                        #(#value_calcs)*
+                        #new_func_ident(#(#func_args),*)
+                    }},
+                    table_access,
+                )
+            }
+            None => table_access,
+        };
+        let (return_ty, body) = match mode {
+            Options::Option => {
+                let in_table = arg_info.iter().map(|arg| {
+                    let index_var = arg.index_var();
+                    let size_ident = arg.size_ident();
+                    quote! { #index_var < #size_ident }
+                });
+                (
+                    option_return_ty.clone(),
+                    quote! {
+                        if !(#(#in_table)&&*) {
+                            return None;
+                        }
+                        #option_wrap(#access)
+                    },
+                )
+            }
+            Options::Fallback | Options::Panic => (quote! { #return_ty }, access),
+        };
+        quote! {
+            /// Reads the look-up table at the indices returned by the
+            /// `_checked` function, without the bounds checks.
+            #inline
+            pub #table_constness fn #by_index_ident(#(#index_params),*) -> #return_ty {
+                #body
+            }
+        }
+    };
+
+    let index_of_ident = format_ident!("{func_ident}_index_of");
+    let index_of_fn = {
+        let (first, rest) = index_vars.split_first().unwrap();
+        let size_idents = arg_info.iter().skip(1).map(Arg::size_ident);
+        quote! {
+            /// Returns the offset of the arguments in the look-up table,
+            /// flattened in row-major order, [None] if any is out of range.
+            pub const fn #index_of_ident(#(#fn_params),*) -> Option<usize> {
+                if !(#bounds_check_expr) {
+                    return None;
+                }
+                #(#index_calcs)*
+                let flat = #first;
+                #(let flat = flat * #size_idents + #rest;)*
+                Some(flat)
+            }
+        }
+    };
+
+    // With `packed`, an array table is also viewed as a single slice, see
+    // `packed_defs`. A `bool` table is stored as bits instead, see `bits_ident`.
+    if let Some(packed) = &packed
+        && storage == Storage::Heap
+    {
+        return Err(syn::Error::new_spanned(
+            packed,
+            "packed can't be combined with `storage = heap`, the table is built at run time",
+        ));
+    }
+    let packed = match packed {
+        Some(packed) if returns_bool => {
+            if arg_info.len() > 1 {
+                return Err(syn::Error::new_spanned(
+                    packed,
+                    "packed stores `bool` values as bits, which requires a single argument",
+                ));
+            }
+            None
+        }
+        Some(packed) => {
+            let item = match storage {
+                Storage::Static => quote! { static },
+                _ => quote! { const },
+            };
+            Some(packed_defs(
+                &packed,
+                &sig,
+                &table_ref,
+                item,
+                &index_of_ident,
+            )?)
+        }
+        None => None,
+    };
+    let (packed_defs, packed_idents) = packed.unzip();
+
+    // The inverse of the row-major offset: the last argument varies fastest.
+    let unindex_fn = {
+        let arg_tys = arg_info.iter().map(|arg| &arg.ty);
+        let index_splits = arg_info.iter().enumerate().rev().map(|(position, arg)| {
+            let index_var = arg.index_var();
+            let size_ident = arg.size_ident();
+            let rest = (position > 0).then(|| quote! { let flat = flat / #size_ident; });
+            quote! {
+                let #index_var = flat % #size_ident;
+                #rest
+            }
+        });
+        let values = arg_info.iter().map(|arg| arg.value(&arg.index_var()));
+        quote! {
+            /// Returns the arguments whose value is stored at `flat`, an offset
+            /// into the look-up table in row-major order.
+            #[track_caller]
+            pub const fn #unindex_ident(flat: usize) -> (#(#arg_tys,)*) {
+                assert!(flat < TABLE_LEN, "flat index is out of the precalculated table");
+                #(#index_splits)*
+                (#(#values,)*)
+            }
+        }
+    };
+
+    // With `opaque_table`, the exported table is wrapped in a type whose
+    // only accessor validates the arguments, e.g. `AddTable` for `add`.
+    let opaque_ident = opaque_table.then(|| {
+        let camel_case = func_ident
+            .to_string()
+            .split('_')
+            .map(|word| {
+                let mut chars = word.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                    .unwrap_or_default()
+            })
+            .collect::<String>();
+        format_ident!("{camel_case}Table")
+    });
+    // Tables stored as bits export the bits.
+    let (exported_ty, exported_ref) = match &bits_ref {
+        Some(bits_ref) => (quote! { [u8; BITS_LEN] }, bits_ref.clone()),
+        None => (quote! { #table_type }, table_ref.clone()),
+    };
+    let export_fn = (export || opaque_table).then(|| {
+        let table_fn_ident = format_ident!("{func_ident}_table");
+        let def = match &opaque_ident {
+            Some(opaque_ident) => {
+                let mut access = widen(index_vars.iter().fold(
+                    quote! { self.0 },
+                    |acc, index_var| quote! { #acc[#index_var] },
+                ));
+                if jagged_elem.is_some() {
+                    access = quote! { jagged_row(#access) };
+                }
+                if bits_ref.is_some() {
+                    access = read_bits(&quote! { self.0 }, &index_vars[0]);
+                }
+                let func_args = func_args.clone();
+                quote! {
+                    /// The precalculated look-up table of the function, only
+                    /// readable through arguments validated against its ranges.
+                    #[repr(transparent)]
+                    pub struct #opaque_ident(#exported_ty);
+
+                    impl #opaque_ident {
+                        /// The precalculated value for the arguments, [None] if
+                        /// any is out of range.
+                        pub const fn get(&self, #(#fn_params),*) -> Option<#return_ty> {
+                            match #checked_ident(#(#func_args),*) {
+                                Some((#(#index_vars,)*)) => Some(#access),
+                                None => None,
+                            }
+                        }
+
+                        /// The number of values of each argument, in order.
+                        pub const fn dims(&self) -> &'static [usize] {
+                            #dims_ident
+                        }
+                    }
+
+                    /// The precalculated look-up table.
+                    pub #table_constness fn #table_fn_ident() -> &'static #opaque_ident {
+                        let table: &'static #exported_ty = #exported_ref;
+                        // `#opaque_ident` is a transparent wrapper of the table.
+                        unsafe { &*(table as *const #exported_ty).cast::<#opaque_ident>() }
+                    }
+                }
+            }
+            None => quote! {
+                /// The precalculated look-up table, indexed by `input - min` for each argument.
+                pub #table_constness fn #table_fn_ident() -> &'static #exported_ty {
+                    #exported_ref
+                }
+            },
+        };
+        (def, table_fn_ident)
+    });
+    let (export_fn, export_ident) = export_fn.unzip();
+
+    let flat_table_fn = flat_table.then(|| {
+        let flat_table_ident = format_ident!("{func_ident}_flat_table");
+        let flattens = arg_info.iter().skip(1).map(|_| quote! { .as_flattened() });
+        (
+            quote! {
+                /// Copies the look-up table into a [recuerdame::flat::FlatTable],
+                /// in row-major order.
+                pub fn #flat_table_ident() -> recuerdame::flat::FlatTable<#return_ty> {
+                    let values = (#table_ref) #(#flattens)* .to_vec();
+                    match recuerdame::flat::FlatTable::new(#dims_ident.to_vec(), values) {
+                        Some(table) => table,
+                        None => unreachable!(),
+                    }
+                }
+            },
+            flat_table_ident,
+        )
+    });
+    let (flat_table_fn, flat_table_ident) = flat_table_fn.unzip();
+
+    // `c_abi` wraps the function in an unmangled `extern "C"` function. It
+    // keeps the semantics of the operating mode, except for `option` whose
+    // return type has no C equivalent.
+    let c_abi_fn = match c_abi {
+        Some((_, value)) if mode == Options::Option => {
+            return Err(syn::Error::new_spanned(
+                value,
+                "c_abi requires the fallback or panic mode, an Option can't cross the C ABI",
+            ));
+        }
+        Some((ident, _)) => {
+            let func_args = func_args.clone();
+            Some((
+                quote! {
+                    /// C ABI wrapper of the precalculated function.
+                    #[unsafe(no_mangle)]
+                    pub extern "C" fn #ident #generics (#(#fn_params),*) -> #return_ty #where_clause {
+                        #func_ident(#(#func_args),*)
+                    }
+                },
+                ident,
+            ))
+        }
+        None => None,
+    };
+    let (c_abi_fn, c_abi_ident) = c_abi_fn.unzip();
+
+    let (value_stats, value_stats_idents) = value_stats
+        .map(|_| value_stats_defs(&sig, &table_ref))
+        .unzip();
+
+    let (store_error_def, store_error_ident) = store_f16
+        .as_ref()
+        .map(|_| store_error_def(&sig, &table_ref))
+        .unzip();
+
+    // `count_misses` counts the inputs out of the table. Recording one is
+    // kept out of line since in range inputs are expected to be the norm.
+    let miss_count = count_misses.map(|_| {
+        let ident = format_ident!("{func_ident}_miss_count");
+        (
+            quote! {
+                static MISSES: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+                #[cold]
+                #[inline(never)]
+                fn record_miss() {
+                    MISSES.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                }
+
+                /// Number of calls whose inputs were out of the look-up table.
+                pub fn #ident() -> u64 {
+                    MISSES.load(std::sync::atomic::Ordering::Relaxed)
+                }
+            },
+            ident,
+        )
+    });
+    let (miss_count_fn, miss_count_ident) = miss_count.unzip();
+
+    let label = label.map(|text| {
+        let ident = format_ident!("{}_LABEL", func_ident.to_string().to_uppercase());
+        (
+            quote! {
+                /// Label given to the look-up table.
+                pub const #ident: &str = #text;
+            },
+            ident,
+        )
+    });
+    let (label_def, label_ident) = label.unzip();
+
+    let exports = std::iter::once(&func_ident)
+        .chain([
+            &checked_ident,
+            &used_table_ident,
+            &by_index_ident,
+            &index_of_ident,
+            &unindex_ident,
+            &covers_full_domain_ident,
+            &dims_ident,
+        ])
+        .chain(&variant_idents)
+        .chain(nearest_ident.iter())
+        .chain(rt_ident.iter())
+        .chain(&default_idents)
+        .chain(build_table_ident.iter())
+        .chain(c_abi_ident.iter())
+        .chain(miss_count_ident.iter())
+        .chain(label_ident.iter())
+        .collect::<Vec<_>>();
+    // Items reading the table, which `release_only` leaves out of debug
+    // builds so the table is never evaluated there.
+    let table_exports = export_ident
+        .iter()
+        .chain(opaque_ident.iter())
+        .chain(flat_table_ident.iter())
+        .chain(packed_idents.iter().flatten())
+        .chain(value_stats_idents.iter().flatten())
+        .chain(store_error_ident.iter())
+        .collect::<Vec<_>>();
+    let release_cfg = release_only
+        .as_ref()
+        .map(|_| quote! { #[cfg(not(debug_assertions))] });
+    let gate = |items: Option<proc_macro2::TokenStream>| {
+        items
+            .map(|items| cfg_items(&release_cfg, items))
+            .transpose()
+    };
+    let generate_table_fn = gate(Some(generate_table_fn))?;
+    let table_def = gate(Some(table_def))?;
+    let byte_bits_def = gate(byte_bits_def)?;
+    let jagged_defs = gate(jagged_defs)?;
+    let table_hash = gate(table_hash)?;
+    let packed_defs = gate(packed_defs)?;
+    let export_fn = gate(export_fn)?;
+    let flat_table_fn = gate(flat_table_fn)?;
+    let value_stats = gate(value_stats)?;
+    let store_error_def = gate(store_error_def)?;
+    let bits_export = bits_ident.iter();
+
+    // `cfg = PREDICATE` gates both the module and the re-export.
+    let cfg_attr = cfg.map(|cfg| quote! { #[cfg(#cfg)] });
+
+    // The original function and the range expressions live in the outer
+    // module, which only glob imports the parent. Everything generated lives
+    // in the inner `_precalc` module so it cannot shadow names they use.
+    let expanded = quote! {
+
+        #cfg_attr
+        mod #mod_name {
+
+            use super::*;
+
+            #func
+
+            #recurrence_fn
+
+            #(#range_defs)*
+
+            #ranges_const_check
+
+            #max_bytes_def
+
+            #fill_def
+
+            #values_def
+
+            pub use self::_precalc::{#(#exports),*};
+
+            #release_cfg
+            pub use self::_precalc::{#lookup_table_ident #(, #bits_export)* #(, #table_exports)*};
+
+            // Generated code shouldn't add to the lints of the user's crate.
+            #[allow(clippy::all, clippy::pedantic)]
+            mod _precalc {
+
+                use super::*;
+
+                #(#const_defs)*
+
+                #table_size_defs
+
+                #values_check
+
+                #generate_table_fn
+
+                #table_def
+
+                #jagged_defs
+
+                #table_hash
+
+                #precalc_fn
+
+                #(#variant_fns)*
+
+                #nearest_fn
+
+                #rt_fn
+
+                #(#default_fns)*
+
+                #checked_fn
+
+                #used_table_fn
+
+                #by_index_fn
+
+                #index_of_fn
+
+                #packed_defs
+
+                #unindex_fn
+
+                #covers_full_domain_def
+
+                #byte_bits_def
+
+                #dims_def
+
+                #export_fn
+
+                #build_table_fn
+
+                #flat_table_fn
+
+                #c_abi_fn
+
+                #value_stats
+
+                #store_error_def
+
+                #miss_count_fn
+
+                #label_def
+            }
+        }
+
+        #cfg_attr
+        #[allow(unused_imports)]
+        #visibility use #mod_name::{#(#exports),*};
+
+        #cfg_attr
+        #release_cfg
+        #[allow(unused_imports)]
+        #visibility use #mod_name::{#(#table_exports),*};
+    };
+
+    Ok(expanded)
+}
+
+/// Expands a method whose `self` is replaced by `self_value` while the table
+/// is built. Associated items can't hold modules, so the usual expansion of
+/// a free function calling the original method goes in the method's body.
+fn expand_method(
+    metas: Punctuated<Meta, Token![,]>,
+    func: ItemFn,
+    self_value: Expr,
+    name: Option<Ident>,
+    mode: Options,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let receiver = func.sig.receiver().unwrap().clone();
+    if receiver.mutability.is_some() || receiver.colon_token.is_some() {
+        return Err(syn::Error::new_spanned(
+            receiver,
+            "self_value requires a `self` or `&self` receiver",
+        ));
+    }
+    let func_ident = name.unwrap_or_else(|| func.sig.ident.clone());
+    let original_ident = format_ident!("_{}_original", func.sig.ident);
+    let flatten_option = metas
+        .iter()
+        .any(|meta| meta.path().is_ident("flatten_option"));
+    // Counting misses isn't `const`, the method can't be either.
+    let constness = (!metas
+        .iter()
+        .any(|meta| meta.path().is_ident("count_misses")))
+    .then(|| quote! { const });
+    // The expansion is in the method's body, so `cfg` gates the method itself.
+    let (cfg, metas) = metas
+        .into_iter()
+        .partition::<Vec<_>, _>(|meta| meta.path().is_ident("cfg"));
+    let metas = metas.into_iter().collect();
+    let cfg_attr = match cfg.first() {
+        Some(Meta::NameValue(mnv)) => {
+            let cfg = &mnv.value;
+            Some(quote! { #[cfg(#cfg)] })
+        }
+        _ => None,
+    };
+    let args = func
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            FnArg::Typed(pat_type) => Some(pat_type),
+            FnArg::Receiver(_) => None,
+        })
+        .collect::<Vec<_>>();
+    let arg_idents = args.iter().map(|arg| &arg.pat).collect::<Vec<_>>();
+    let (_, _, where_clause) = func.sig.generics.split_for_impl();
+    let generics = &func.sig.generics;
+    let output = &func.sig.output;
+
+    let mut original = func.clone();
+    original.sig.ident = original_ident.clone();
+    original.vis = Visibility::Inherited;
+    original.attrs.push(parse_quote! { #[doc(hidden)] });
+
+    let free_fn: ItemFn = parse_quote! {
+        const fn #func_ident #generics (#(#args),*) #output #where_clause {
+            (#self_value).#original_ident(#(#arg_idents),*)
+        }
+    };
+    let expanded = expand(metas, free_fn)?;
+
+    let return_ty = match output {
+        syn::ReturnType::Default => quote! { () },
+        syn::ReturnType::Type(_, ty) => ty.to_token_stream(),
+    };
+    let (track_caller, return_ty) = match mode {
+        Options::Panic => (Some(quote! { #[track_caller] }), return_ty),
+        Options::Fallback => (None, return_ty),
+        Options::Option if flatten_option => (None, return_ty),
+        Options::Option => (None, quote! { Option<#return_ty> }),
+    };
+    let ItemFn { attrs, vis, .. } = func;
+    Ok(quote! {
+        #cfg_attr
+        #original
+
+        #cfg_attr
+        #(#attrs)*
+        #[inline]
+        #track_caller
+        #vis #constness fn #func_ident #generics (#receiver, #(#args),*) -> #return_ty #where_clause {
+            #expanded
+            #func_ident(#(#arg_idents),*)
+        }
+    })
 }
@@ -0,0 +1,44 @@
+//! Differential fuzzing of a precalculated function against its original.
+//!
+//! With `cargo fuzz` the body of `fuzz` goes in the fuzz target:
+//!
+//! ```ignore
+//! libfuzzer_sys::fuzz_target!(|data: &[u8]| fuzz(data));
+//! ```
+//!
+//! This example runs the same check over pseudo random data instead:
+//!
+//! ```sh
+//! cargo run --example fuzz_target --features arbitrary
+//! ```
+
+use arbitrary::{Arbitrary, Unstructured};
+use recuerdame::{precalc_assert_eq, precalculate};
+
+#[precalculate(a = 0..=100, b = -50..=50)]
+const fn weighted(a: i32, b: i32) -> i64 {
+    a as i64 * 3 - b as i64 * b as i64
+}
+
+fn fuzz(data: &[u8]) {
+    let mut input = Unstructured::new(data);
+    let Ok((a, b)) = <(i32, i32)>::arbitrary(&mut input) else {
+        return;
+    };
+    precalc_assert_eq!(weighted, (a % 200, b % 200));
+}
+
+fn main() {
+    let mut state = 0x2545_f491_u32;
+    let mut data = [0; 8];
+    for _ in 0..100_000 {
+        for byte in &mut data {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            *byte = state as u8;
+        }
+        fuzz(&data);
+    }
+    println!("precalculated `weighted` matches the original");
+}
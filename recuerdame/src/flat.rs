@@ -0,0 +1,82 @@
+//! Look-up tables flattened in row-major order.
+//!
+//! The `flat_table` option of `#[precalculate]` generates
+//! `<name>_flat_table()`, returning a copy of the look-up table as a
+//! [FlatTable]. With the `serde` feature it implements `Serialize` and
+//! `Deserialize`, so a table can be persisted or transmitted and looked up at
+//! run time with the indices returned by `<name>_checked`.
+//!
+//! The serialized form holds the size of each dimension and the values, the
+//! last argument varying fastest:
+//!
+//! ```json
+//! { "dims": [2, 3], "values": [0, 1, 2, 10, 11, 12] }
+//! ```
+
+/// A look-up table of any number of dimensions stored as a flat vector in
+/// row-major order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "FlatTableData<T>")
+)]
+pub struct FlatTable<T> {
+    dims: Vec<usize>,
+    values: Vec<T>,
+}
+
+/// Unvalidated contents of a deserialized [FlatTable].
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct FlatTableData<T> {
+    dims: Vec<usize>,
+    values: Vec<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<FlatTableData<T>> for FlatTable<T> {
+    type Error = &'static str;
+
+    fn try_from(data: FlatTableData<T>) -> Result<Self, Self::Error> {
+        FlatTable::new(data.dims, data.values)
+            .ok_or("the number of values doesn't match the dimensions of the table")
+    }
+}
+
+impl<T> FlatTable<T> {
+    /// Creates a table from the size of each dimension and the values in
+    /// row-major order, [None] if their number doesn't match the dimensions.
+    pub fn new(dims: Vec<usize>, values: Vec<T>) -> Option<Self> {
+        let len = dims
+            .iter()
+            .try_fold(1usize, |len, dim| len.checked_mul(*dim))?;
+        (len == values.len()).then_some(FlatTable { dims, values })
+    }
+
+    /// Size of each dimension, in argument order.
+    pub fn dims(&self) -> &[usize] {
+        &self.dims
+    }
+
+    /// The values in row-major order.
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// The value at the given index in each dimension, [None] if any is out
+    /// of bounds.
+    pub fn get(&self, indices: &[usize]) -> Option<&T> {
+        if indices.len() != self.dims.len() {
+            return None;
+        }
+        let mut offset = 0;
+        for (index, dim) in indices.iter().zip(&self.dims) {
+            if index >= dim {
+                return None;
+            }
+            offset = offset * dim + index;
+        }
+        self.values.get(offset)
+    }
+}
@@ -0,0 +1,254 @@
+//! Freezes a look-up table into Rust source code.
+//!
+//! `#[precalculate]` builds its table every time the crate is compiled. For
+//! large tables this can be avoided by generating the table once, from a
+//! `build.rs` script or a small binary, and committing the resulting file:
+//!
+//! ```rust
+//! use recuerdame::freeze::FrozenTable;
+//!
+//! const fn add(a: i32, b: i32) -> i32 {
+//!     a + b
+//! }
+//!
+//! let source = FrozenTable::new("add", "i32")
+//!     .arg("a", "i32", 0, 10)
+//!     .arg("b", "i32", 0, 4)
+//!     .generate(|args| add(args[0] as i32, args[1] as i32));
+//! assert!(source.contains("pub const fn add(a: i32, b: i32) -> Option<i32>"));
+//! ```
+//!
+//! The file is then used with `include!`. It contains the table as a literal
+//! array and a lookup function in `option` mode, since the original function
+//! is not part of the frozen file.
+//!
+//! Instead of committing the file, a `build.rs` script can write it to
+//! `OUT_DIR` with [FrozenTable::write_cached]. The file starts with a hash of
+//! the name, types and ranges of the table, of the version of this crate, and
+//! of the source given to [FrozenTable::key], such as the function body. The
+//! key is required, since the hash cannot see the function otherwise. While
+//! they are unchanged the file is kept and the values are not computed again:
+//!
+//! ```rust,no_run
+//! use recuerdame::freeze::FrozenTable;
+//!
+//! const fn add(a: i32, b: i32) -> i32 {
+//!     a + b
+//! }
+//!
+//! let out_dir = std::env::var("OUT_DIR").unwrap();
+//! FrozenTable::new("add", "i32")
+//!     .arg("a", "i32", 0, 10)
+//!     .arg("b", "i32", 0, 4)
+//!     .key(&std::fs::read_to_string("src/add.rs").unwrap())
+//!     .write_cached(format!("{out_dir}/add_table.rs"), |args| {
+//!         add(args[0] as i32, args[1] as i32)
+//!     })
+//!     .unwrap();
+//! println!("cargo::rerun-if-changed=src/add.rs");
+//! ```
+//!
+//! The crate then uses `include!(concat!(env!("OUT_DIR"), "/add_table.rs"))`.
+
+use std::fmt::{Debug, Write};
+use std::io;
+use std::path::Path;
+
+/// Version of the generated source, part of [FrozenTable::cache_key] so files
+/// cached by an older layout are written again.
+const FORMAT_VERSION: u32 = 1;
+
+/// An argument of a frozen function and its inclusive range.
+struct FrozenArg {
+    name: String,
+    ty: String,
+    min: i128,
+    max: i128,
+}
+
+/// Builder for the source of a frozen look-up table.
+pub struct FrozenTable {
+    name: String,
+    return_ty: String,
+    args: Vec<FrozenArg>,
+    key: String,
+}
+
+impl FrozenTable {
+    /// Starts a frozen function named `name` returning `return_ty`.
+    pub fn new(name: &str, return_ty: &str) -> Self {
+        FrozenTable {
+            name: name.to_string(),
+            return_ty: return_ty.to_string(),
+            args: Vec::new(),
+            key: String::new(),
+        }
+    }
+
+    /// Adds an integer argument precalculated over `min..=max`.
+    pub fn arg(mut self, name: &str, ty: &str, min: i128, max: i128) -> Self {
+        assert!(min <= max, "precalculated range is empty");
+        self.args.push(FrozenArg {
+            name: name.to_string(),
+            ty: ty.to_string(),
+            min,
+            max,
+        });
+        self
+    }
+
+    /// Adds `source` to the cache key of [FrozenTable::write_cached], so the
+    /// file is generated again when it changes. It is usually the source of
+    /// the function computing the values.
+    pub fn key(mut self, source: &str) -> Self {
+        self.key.push_str(source);
+        self
+    }
+
+    /// 64-bit FNV-1a hash of the name, types and ranges of the table, of the
+    /// crate and format versions, and of the sources given to
+    /// [FrozenTable::key].
+    pub fn cache_key(&self) -> u64 {
+        let mut description = format!(
+            "recuerdame {} format {FORMAT_VERSION}\n{}\n{}\n",
+            env!("CARGO_PKG_VERSION"),
+            self.name,
+            self.return_ty
+        );
+        for arg in &self.args {
+            writeln!(
+                description,
+                "{}: {} = {}..={}",
+                arg.name, arg.ty, arg.min, arg.max
+            )
+            .unwrap();
+        }
+        description.push_str(&self.key);
+        crate::__private::fnv1a(description.as_bytes())
+    }
+
+    /// Writes the source of the frozen table to `path`, unless the file there
+    /// was written for the same [FrozenTable::cache_key], in which case `f`
+    /// is not called. Returns whether the file was written.
+    ///
+    /// Panics if no source was given to [FrozenTable::key], as a change to
+    /// `f` alone would not be noticed.
+    pub fn write_cached<T: Debug>(
+        &self,
+        path: impl AsRef<Path>,
+        f: impl Fn(&[i128]) -> T,
+    ) -> io::Result<bool> {
+        assert!(
+            !self.key.is_empty(),
+            "a cached frozen table needs a key, add the source of the function with `key`"
+        );
+        let header = format!("// recuerdame cache key: {:#018x}\n", self.cache_key());
+        let path = path.as_ref();
+        if let Ok(existing) = std::fs::read_to_string(path)
+            && existing.starts_with(&header)
+        {
+            return Ok(false);
+        }
+        std::fs::write(path, header + &self.generate(f))?;
+        Ok(true)
+    }
+
+    /// Evaluates `f` for every combination of arguments, passed in
+    /// declaration order, and returns the source of the frozen table.
+    ///
+    /// Values are written with their [Debug] representation, which must be a
+    /// valid Rust expression of the return type.
+    ///
+    /// Panics if no argument was added.
+    pub fn generate<T: Debug>(&self, f: impl Fn(&[i128]) -> T) -> String {
+        assert!(
+            !self.args.is_empty(),
+            "a frozen table needs at least one argument, add one with `arg`"
+        );
+        let table_ident = format!("LOOKUP_TABLE_{}", self.name.to_uppercase());
+        let table_ty = self
+            .args
+            .iter()
+            .rev()
+            .fold(self.return_ty.clone(), |inner, arg| {
+                format!("[{inner}; {}]", arg.max - arg.min + 1)
+            });
+
+        let mut table = String::new();
+        self.write_values(&mut table, &mut Vec::new(), &f);
+
+        let params = self
+            .args
+            .iter()
+            .map(|arg| format!("{}: {}", arg.name, arg.ty))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let bounds_check = self
+            .args
+            .iter()
+            .map(|arg| {
+                format!(
+                    "{min} <= {name} && {name} <= {max}",
+                    name = arg.name,
+                    min = arg.min,
+                    max = arg.max
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" && ");
+        // The offset is computed in `i128`, as it may not fit the argument's
+        // type, e.g. `100 - -100` for an `i8`.
+        let table_access = self.args.iter().fold(table_ident.clone(), |acc, arg| {
+            format!("{acc}[({} as i128 - {}) as usize]", arg.name, arg.min)
+        });
+
+        let mut source = String::new();
+        writeln!(source, "// @generated by recuerdame::freeze, do not edit.").unwrap();
+        writeln!(source).unwrap();
+        writeln!(source, "const {table_ident}: {table_ty} = {table};").unwrap();
+        writeln!(source).unwrap();
+        writeln!(
+            source,
+            "pub const fn {}({params}) -> Option<{}> {{",
+            self.name, self.return_ty
+        )
+        .unwrap();
+        writeln!(source, "    if !({bounds_check}) {{").unwrap();
+        writeln!(source, "        return None;").unwrap();
+        writeln!(source, "    }}").unwrap();
+        writeln!(source, "    Some({table_access})").unwrap();
+        writeln!(source, "}}").unwrap();
+        source
+    }
+
+    /// Writes the (nested) array of values for the arguments after `prefix`.
+    fn write_values<T: Debug>(
+        &self,
+        out: &mut String,
+        prefix: &mut Vec<i128>,
+        f: &impl Fn(&[i128]) -> T,
+    ) {
+        let arg = &self.args[prefix.len()];
+        let innermost = prefix.len() + 1 == self.args.len();
+        let indent = "    ".repeat(prefix.len() + 1);
+        out.push('[');
+        for value in arg.min..=arg.max {
+            prefix.push(value);
+            if innermost {
+                if value != arg.min {
+                    out.push_str(", ");
+                }
+                write!(out, "{:?}", f(prefix)).unwrap();
+            } else {
+                write!(out, "\n{indent}").unwrap();
+                self.write_values(out, prefix, f);
+                out.push(',');
+            }
+            prefix.pop();
+        }
+        if !innermost {
+            write!(out, "\n{}", "    ".repeat(prefix.len())).unwrap();
+        }
+        out.push(']');
+    }
+}
@@ -1,7 +1,208 @@
-pub use recuerdame_macros::precalculate;
+pub use recuerdame_macros::{
+    fuse, precalc_assert_approx_eq, precalc_assert_eq, precalculate, precalculate_closure,
+    precalculate_struct,
+};
 
 extern crate self as recuerdame;
 
+pub mod flat;
+pub mod freeze;
+
+#[doc(hidden)]
+pub mod __private {
+    /// Storage type of `store = f16`.
+    #[cfg(feature = "half")]
+    pub use half::f16;
+
+    const MESSAGE_CAPACITY: usize = 160;
+
+    /// Fixed capacity string builder usable in const contexts.
+    struct Message {
+        bytes: [u8; MESSAGE_CAPACITY],
+        len: usize,
+    }
+
+    impl Message {
+        const fn new() -> Self {
+            Message {
+                bytes: [0; MESSAGE_CAPACITY],
+                len: 0,
+            }
+        }
+
+        const fn push_str(mut self, s: &str) -> Self {
+            let s = s.as_bytes();
+            let mut i = 0;
+            while i < s.len() && self.len < MESSAGE_CAPACITY {
+                self.bytes[self.len] = s[i];
+                self.len += 1;
+                i += 1;
+            }
+            self
+        }
+
+        const fn push_int(mut self, value: i128) -> Self {
+            if value < 0 {
+                self = self.push_str("-");
+            }
+            let mut digits = [0u8; 39];
+            let mut count = 0;
+            let mut n = value.unsigned_abs();
+            loop {
+                digits[count] = b'0' + (n % 10) as u8;
+                count += 1;
+                n /= 10;
+                if n == 0 {
+                    break;
+                }
+            }
+            while count > 0 && self.len < MESSAGE_CAPACITY {
+                count -= 1;
+                self.bytes[self.len] = digits[count];
+                self.len += 1;
+            }
+            self
+        }
+
+        const fn push_hex(mut self, value: u64) -> Self {
+            self = self.push_str("0x");
+            let mut shift = 64;
+            while shift > 0 && self.len < MESSAGE_CAPACITY {
+                shift -= 4;
+                self.bytes[self.len] = b"0123456789abcdef"[((value >> shift) & 0xf) as usize];
+                self.len += 1;
+            }
+            self
+        }
+
+        #[track_caller]
+        const fn panic(&self) -> ! {
+            let (message, _) = self.bytes.split_at(self.len);
+            match core::str::from_utf8(message) {
+                Ok(message) => panic!("{}", message),
+                Err(_) => panic!("argument out of the precalculated range"),
+            }
+        }
+    }
+
+    /// Panics with a message naming the integer argument and its value.
+    #[track_caller]
+    pub const fn out_of_range(arg: &str, value: i128) -> ! {
+        Message::new()
+            .push_str("argument `")
+            .push_str(arg)
+            .push_str("` = ")
+            .push_int(value)
+            .push_str(" is out of the precalculated range")
+            .panic()
+    }
+
+    /// Panics with a message naming the argument, for non-integer arguments.
+    #[track_caller]
+    pub const fn variant_out_of_range(arg: &str) -> ! {
+        Message::new()
+            .push_str("argument `")
+            .push_str(arg)
+            .push_str("` is out of the precalculated range")
+            .panic()
+    }
+
+    /// 64-bit FNV-1a hash of `bytes`.
+    pub const fn fnv1a(bytes: &[u8]) -> u64 {
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        let mut i = 0;
+        while i < bytes.len() {
+            hash ^= bytes[i] as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+            i += 1;
+        }
+        hash
+    }
+
+    /// Panics with the hash of a look-up table that doesn't match `expect_hash`.
+    #[track_caller]
+    pub const fn hash_mismatch(hash: u64) -> ! {
+        Message::new()
+            .push_str("the look-up table hash is \"")
+            .push_hex(hash)
+            .push_str("\", which doesn't match expect_hash")
+            .panic()
+    }
+}
+
+/// Asserts at compile time that a call to a precalculated function returns
+/// a value matching a pattern.
+///
+/// `assert_precalc!(add(3, 2) => 5)` expands to a `const` item, so it can be
+/// used wherever items can, such as doctests or function bodies, and a wrong
+/// value fails the build. The pattern form also covers return types that
+/// can't be compared in const contexts, such as `Some(5)`.
+///
+/// Example:
+/// ```rust
+/// use recuerdame::{assert_precalc, precalculate};
+///
+/// #[precalculate(a = 0..=10, b = 0..=4, option)]
+/// const fn add(a: i32, b: i32) -> i32 {
+///     a + b
+/// }
+///
+/// assert_precalc!(add(3, 2) => Some(5));
+/// assert_precalc!(add(20, 2) => None);
+/// assert_precalc!(add_unindex(17) => (3, 2));
+/// # fn main() {}
+/// ```
+///
+/// A wrong value is a compile error:
+/// ```compile_fail
+/// use recuerdame::{assert_precalc, precalculate};
+///
+/// #[precalculate(a = 0..=10)]
+/// const fn double(a: u8) -> u8 {
+///     a * 2
+/// }
+///
+/// assert_precalc!(double(3) => 7);
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! assert_precalc {
+    ($call:expr => $expected:pat) => {
+        const _: () = assert!(
+            matches!($call, $expected),
+            concat!(
+                "precalculated value of `",
+                stringify!($call),
+                "` doesn't match `",
+                stringify!($expected),
+                "`"
+            )
+        );
+    };
+}
+
+/// The default epsilon of [precalc_assert_approx_eq].
+pub const DEFAULT_EPSILON: f64 = 1e-6;
+
+/// Whether two floats are within `epsilon` of each other.
+///
+/// Equal values, including infinities of the same sign, and two NaNs are
+/// considered equal, since a look-up table stores exactly what the original
+/// function returned.
+///
+/// Example:
+/// ```rust
+/// use recuerdame::{DEFAULT_EPSILON, approx_eq};
+///
+/// assert!(approx_eq(0.1_f32 + 0.2, 0.3_f32, DEFAULT_EPSILON));
+/// assert!(approx_eq(f64::NAN, f64::NAN, DEFAULT_EPSILON));
+/// assert!(!approx_eq(1.0, 1.1, 0.01));
+/// ```
+pub fn approx_eq<T: Into<f64>>(a: T, b: T, epsilon: f64) -> bool {
+    let (a, b) = (a.into(), b.into());
+    a == b || (a.is_nan() && b.is_nan()) || (a - b).abs() <= epsilon
+}
+
 /// This trait is needed for the return types of precalculated functions.
 /// This tells the crate how to pre-populate the look-up table at compile
 /// time.
@@ -40,10 +241,62 @@ pub trait PrecalcConst {
     const DEFAULT: Self;
 }
 
+/// This trait is needed for arguments of precalculated functions that are
+/// not integers or `char`, such as fieldless enums. It lists every value of
+/// the type so the look-up table can be populated at compile time.
+///
+/// The variants must be listed in ascending order of their discriminants,
+/// which must be contiguous. This is checked at compile time.
+///
+/// It is implemented for [bool] and [core::cmp::Ordering].
+///
+/// Example:
+/// ```rust
+/// use core::cmp::Ordering;
+/// use recuerdame::precalculate;
+///
+/// #[precalculate(o = Ordering::Less..=Ordering::Greater, n = 0..=10)]
+/// const fn pick(o: Ordering, n: u8) -> u8 {
+///     match o {
+///         Ordering::Less => n,
+///         Ordering::Equal => 0,
+///         Ordering::Greater => 10 - n,
+///     }
+/// }
+///
+/// fn main() {
+///     assert_eq!(pick(Ordering::Less, 3), 3);
+///     assert_eq!(pick(Ordering::Greater, 3), 7);
+/// }
+/// ```
+pub trait PrecalcEnum: Copy + 'static {
+    const VARIANTS: &'static [Self];
+}
+
+impl PrecalcEnum for bool {
+    const VARIANTS: &'static [Self] = &[false, true];
+}
+
+impl PrecalcEnum for core::cmp::Ordering {
+    const VARIANTS: &'static [Self] = &[
+        core::cmp::Ordering::Less,
+        core::cmp::Ordering::Equal,
+        core::cmp::Ordering::Greater,
+    ];
+}
+
 impl<T> PrecalcConst for Option<T> {
     const DEFAULT: Self = None;
 }
 
+impl<T: PrecalcConst, const N: usize> PrecalcConst for [T; N] {
+    const DEFAULT: Self = [T::DEFAULT; N];
+}
+
+impl<T: PrecalcConst> PrecalcConst for core::num::Wrapping<T> {
+    const DEFAULT: Self = core::num::Wrapping(T::DEFAULT);
+}
+
 macro_rules! impl_precalc_const_for_tuple {
     ($($T:ident),+) => {
         impl<$($T),*> PrecalcConst for ($($T,)*)
@@ -103,3 +356,20 @@ impl_precalc_const_int!(i128);
 
 impl_precalc_const_float!(f32);
 impl_precalc_const_float!(f64);
+
+#[cfg(feature = "half")]
+impl PrecalcConst for half::f16 {
+    const DEFAULT: Self = half::f16::ZERO;
+}
+
+impl PrecalcConst for () {
+    const DEFAULT: Self = ();
+}
+
+impl PrecalcConst for bool {
+    const DEFAULT: Self = false;
+}
+
+impl PrecalcConst for char {
+    const DEFAULT: Self = '\0';
+}
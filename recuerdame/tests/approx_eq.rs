@@ -0,0 +1,54 @@
+use recuerdame::{DEFAULT_EPSILON, approx_eq, precalc_assert_approx_eq, precalculate};
+
+#[precalculate(x = 0..=200)]
+const fn celsius_to_fahrenheit(x: u8) -> f32 {
+    x as f32 * 1.8 + 32.0
+}
+
+#[precalculate(a = -10..=10, b = 1..=8)]
+const fn ratio(a: i8, b: i8) -> f64 {
+    a as f64 / b as f64
+}
+
+#[test]
+fn float_tables_match_the_original_within_the_default_epsilon() {
+    for x in 0..=u8::MAX {
+        precalc_assert_approx_eq!(celsius_to_fahrenheit, (x));
+    }
+    for a in -20..=20 {
+        for b in 1..=10 {
+            precalc_assert_approx_eq!(ratio, (a, b));
+        }
+    }
+}
+
+#[test]
+fn epsilon_can_be_set() {
+    precalc_assert_approx_eq!(celsius_to_fahrenheit, (100), verify_epsilon = 1e-3);
+    precalc_assert_approx_eq!(ratio, (3, 7), verify_epsilon = 0.0,);
+}
+
+#[test]
+fn approx_eq_tolerates_only_epsilon() {
+    assert!(approx_eq(1.0, 1.0 + DEFAULT_EPSILON / 2.0, DEFAULT_EPSILON));
+    assert!(!approx_eq(1.0, 1.0 + DEFAULT_EPSILON * 2.0, DEFAULT_EPSILON));
+    assert!(approx_eq(f32::INFINITY, f32::INFINITY, 0.0));
+    assert!(!approx_eq(f32::INFINITY, f32::NEG_INFINITY, DEFAULT_EPSILON));
+    assert!(!approx_eq(f64::NAN, 0.0, DEFAULT_EPSILON));
+}
+
+#[test]
+#[should_panic(expected = "differs from the original function")]
+fn mismatch_panics() {
+    mod shifted {
+        pub mod _mod_precalc_half {
+            pub fn _half_original(x: u8) -> f32 {
+                x as f32 / 2.0 + 0.01
+            }
+        }
+        pub fn half(x: u8) -> f32 {
+            x as f32 / 2.0
+        }
+    }
+    precalc_assert_approx_eq!(shifted::half, (3), verify_epsilon = 1e-3);
+}
@@ -0,0 +1,102 @@
+use recuerdame::precalculate;
+
+const fn xorshift(mut state: u32) -> u32 {
+    state ^= state << 13;
+    state ^= state >> 17;
+    state ^= state << 5;
+    state
+}
+
+/// Buckets 64 pseudo random numbers derived from `seed` into 16 bins.
+#[precalculate(seed = 0..=u8::MAX, storage = static)]
+const fn histogram(seed: u8) -> [u32; 16] {
+    let mut bins = [0; 16];
+    let mut state = seed as u32 + 1;
+    let mut i = 0;
+    while i < 64 {
+        state = xorshift(state);
+        bins[(state % 16) as usize] += 1;
+        i += 1;
+    }
+    bins
+}
+
+#[precalculate(n = 0..=4, option)]
+const fn powers(n: u64) -> [[u64; 3]; 2] {
+    [[1, n, n * n], [n * n * n, n * n * n * n, n * n * n * n * n]]
+}
+
+#[test]
+fn array_returns_match_original() {
+    for seed in 0..=u8::MAX {
+        let bins = histogram(seed);
+        assert_eq!(bins, _mod_precalc_histogram::_histogram_original(seed));
+        assert_eq!(bins.iter().sum::<u32>(), 64);
+    }
+}
+
+#[test]
+fn nested_array_returns() {
+    assert_eq!(powers(2), Some([[1, 2, 4], [8, 16, 32]]));
+    assert_eq!(powers(5), None);
+}
+
+#[test]
+fn array_default_is_filled_with_element_default() {
+    assert_eq!(<[u8; 4] as recuerdame::PrecalcConst>::DEFAULT, [0; 4]);
+    assert_eq!(<[Option<char>; 2] as recuerdame::PrecalcConst>::DEFAULT, [None, None]);
+}
+
+/// Two SIMD style lanes of four values each.
+#[precalculate(i = 0..=7)]
+const fn lane(i: u8) -> ([f32; 4], [f32; 4]) {
+    let base = i as f32 * 4.0;
+    (
+        [base, base + 1.0, base + 2.0, base + 3.0],
+        [-base, -base - 1.0, -base - 2.0, -base - 3.0],
+    )
+}
+
+#[test]
+fn tuple_of_arrays_returns() {
+    for i in 0..=7 {
+        assert_eq!(lane(i), _mod_precalc_lane::_lane_original(i));
+    }
+    assert_eq!(lane(1), ([4.0, 5.0, 6.0, 7.0], [-4.0, -5.0, -6.0, -7.0]));
+}
+
+#[test]
+fn tuple_of_arrays_default_composes() {
+    assert_eq!(
+        <([f32; 4], [f32; 4]) as recuerdame::PrecalcConst>::DEFAULT,
+        ([0.0; 4], [0.0; 4])
+    );
+    assert_eq!(
+        <([u8; 2], [Option<u8>; 1], ()) as recuerdame::PrecalcConst>::DEFAULT,
+        ([0, 0], [None], ())
+    );
+}
+
+const ROW_LEN: usize = 8;
+
+/// One row of an 8x8 multiplication table, its length is a named const that
+/// also bounds the argument.
+#[precalculate(i = 0..=(ROW_LEN as u8 - 1), export)]
+const fn row(i: u8) -> [u8; ROW_LEN] {
+    let mut row = [0; ROW_LEN];
+    let mut j = 0;
+    while j < ROW_LEN {
+        row[j] = i * j as u8;
+        j += 1;
+    }
+    row
+}
+
+#[test]
+fn const_sized_array_returns() {
+    let table: &[[u8; ROW_LEN]; ROW_LEN] = row_table();
+    assert_eq!(ROW_DIMS, [ROW_LEN]);
+    assert_eq!(size_of_val(table), ROW_LEN * ROW_LEN);
+    assert_eq!(row(3), [0, 3, 6, 9, 12, 15, 18, 21]);
+    assert_eq!(table[7], _mod_precalc_row::_row_original(7));
+}
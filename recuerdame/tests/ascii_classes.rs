@@ -0,0 +1,61 @@
+use recuerdame::precalculate;
+
+#[precalculate(c = 0..=127, export)]
+const fn is_ident_char(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_'
+}
+
+#[precalculate(c = 0..=127, option)]
+const fn is_hex_digit(c: u8) -> bool {
+    c.is_ascii_hexdigit()
+}
+
+// Eight characters per byte, 16 bytes for the whole ASCII range.
+#[precalculate(c = 0..=127, packed, export)]
+const fn is_ident_char_packed(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_'
+}
+
+#[precalculate(c = 0..=127, packed, storage = static, opaque_table)]
+const fn is_space(c: u8) -> bool {
+    c.is_ascii_whitespace()
+}
+
+#[test]
+fn classification_matches_std() {
+    for c in 0..=127u8 {
+        assert_eq!(is_ident_char(c), c.is_ascii_alphanumeric() || c == b'_');
+        assert_eq!(is_hex_digit(c), Some(c.is_ascii_hexdigit()));
+        assert_eq!(is_ident_char_packed(c), is_ident_char(c));
+        assert_eq!(is_space(c), c.is_ascii_whitespace());
+        assert_eq!(is_space_table().get(c), Some(c.is_ascii_whitespace()));
+    }
+}
+
+#[test]
+fn non_ascii_bytes() {
+    // Fallback mode calls the original function, option mode returns `None`.
+    assert!(!is_ident_char(0xc3));
+    assert_eq!(is_hex_digit(0xc3), None);
+    assert!(!is_ident_char_packed(0xc3));
+    assert_eq!(is_space_table().get(0xc3), None);
+}
+
+#[test]
+fn table_sizes() {
+    assert_eq!(size_of_val(is_ident_char_table()), 128);
+    assert_eq!(size_of_val(is_ident_char_packed_table()), 16);
+    assert_eq!(size_of_val(is_space_table()), 16);
+    assert_eq!(
+        size_of_val(&_mod_precalc_is_space::LOOKUP_TABLE_IS_SPACE_BITS),
+        16
+    );
+}
+
+#[test]
+fn packed_bits_follow_the_table_order() {
+    let bits = is_ident_char_packed_table();
+    for c in 0..=127u8 {
+        assert_eq!(bits[c as usize / 8] >> (c % 8) & 1 != 0, is_ident_char(c));
+    }
+}
@@ -0,0 +1,34 @@
+use recuerdame::{precalc_assert_eq, precalculate};
+
+#[precalculate(a = 0..=10, b = -4..=4)]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[precalculate(x = 0..=255, panic)]
+const fn parity(x: u8) -> bool {
+    x.count_ones() % 2 == 1
+}
+
+#[test]
+fn matches_original_in_and_out_of_range() {
+    for a in -5..=15 {
+        for b in -8..=8 {
+            precalc_assert_eq!(add, (a, b));
+        }
+    }
+}
+
+#[test]
+fn single_argument() {
+    for x in 0..=u8::MAX {
+        precalc_assert_eq!(parity, (x));
+        precalc_assert_eq!(parity, (x,));
+    }
+}
+
+#[test]
+fn qualified_path() {
+    precalc_assert_eq!(crate::add, (2, 2));
+    precalc_assert_eq!(self::add, (20, 2));
+}
@@ -0,0 +1,43 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = 0..=4, assume_in_range, variants(option))]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[precalculate(x = 0..=100 by 10, assume_in_range)]
+const fn tenth(x: u8) -> u8 {
+    x / 10
+}
+
+#[precalculate(n = 0..=3, small = match, assume_in_range)]
+const fn bit(n: u8) -> u8 {
+    1 << n
+}
+
+// Only in range inputs are passed, anything else is undefined behavior in
+// release builds.
+#[test]
+fn in_range_inputs_read_the_table() {
+    for a in 0..=10 {
+        for b in 0..=4 {
+            assert_eq!(add(a, b), _mod_precalc_add::_add_original(a, b));
+        }
+    }
+    assert_eq!(tenth(40), 4);
+    assert_eq!(tenth_nearest(46), 5);
+    assert_eq!(bit(3), 8);
+}
+
+const SUM: i32 = add(7, 3);
+
+#[test]
+fn usable_in_constants() {
+    assert_eq!(SUM, 10);
+}
+
+#[test]
+fn variants_keep_their_checks() {
+    assert_eq!(add_opt(11, 0), None);
+    assert_eq!(add_checked(0, 5), None);
+}
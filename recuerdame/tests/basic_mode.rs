@@ -0,0 +1,23 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = 0..=4, basic)]
+const fn add_basic(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[precalculate(a = 0..=10, b = 0..=4)]
+const fn add_default(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[test]
+fn basic_is_the_default_mode() {
+    let basic: fn(i32, i32) -> i32 = add_basic;
+    let default: fn(i32, i32) -> i32 = add_default;
+    for a in -5..=15 {
+        for b in -5..=10 {
+            assert_eq!(basic(a, b), default(a, b));
+        }
+    }
+    assert_eq!(ADD_BASIC_DIMS, ADD_DEFAULT_DIMS);
+}
@@ -0,0 +1,23 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = -4..=4, export, build_table)]
+const fn mul(a: i32, b: i32) -> i32 {
+    a * b
+}
+
+#[precalculate(x = 0..=100 by 10, build_table, option)]
+const fn half(x: u8) -> u8 {
+    x / 2
+}
+
+#[test]
+fn runtime_table_matches_const_table() {
+    assert_eq!(&mul_build_table(), mul_table());
+}
+
+#[test]
+fn runtime_table_of_stepped_range() {
+    let table = half_build_table();
+    assert_eq!(table.len(), 11);
+    assert_eq!(table[3], half(30).unwrap());
+}
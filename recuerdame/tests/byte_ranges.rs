@@ -0,0 +1,34 @@
+use recuerdame::precalculate;
+
+#[precalculate(c = b'a'..=b'z', variants(option, panic))]
+const fn to_upper(c: u8) -> u8 {
+    c.to_ascii_uppercase()
+}
+
+#[precalculate(c = b'0'..b':', small = match, option)]
+const fn digit(c: u8) -> u8 {
+    c - b'0'
+}
+
+#[test]
+fn byte_literal_range() {
+    assert_eq!(TO_UPPER_DIMS, &[26]);
+    for c in b'a'..=b'z' {
+        assert_eq!(to_upper(c), c - 32);
+        assert_eq!(to_upper_opt(c), Some(c - 32));
+    }
+}
+
+#[test]
+fn byte_out_of_range_follows_mode() {
+    assert_eq!(to_upper(b'A'), b'A');
+    assert_eq!(to_upper_opt(b'A'), None);
+    assert!(std::panic::catch_unwind(|| to_upper_panic(b'A')).is_err());
+    assert_eq!(to_upper_checked(b'{'), None);
+}
+
+#[test]
+fn byte_literal_exclusive_small_match() {
+    assert_eq!(digit(b'7'), Some(7));
+    assert_eq!(digit(b':'), None);
+}
@@ -0,0 +1,75 @@
+use recuerdame::precalculate;
+
+#[precalculate(b = 0..=255)]
+const fn reverse_bits(b: u8) -> u8 {
+    b.reverse_bits()
+}
+
+#[precalculate(b = 0..=255, export)]
+const fn is_base64(b: u8) -> bool {
+    matches!(b, b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'+' | b'/')
+}
+
+#[precalculate(b = 0..=254, export)]
+const fn is_even(b: u8) -> bool {
+    b % 2 == 0
+}
+
+#[precalculate(b = -128..=127, panic)]
+const fn is_odd(b: i8) -> bool {
+    b % 2 != 0
+}
+
+#[precalculate(b = 0..=255, storage = static, option)]
+const fn is_power_of_two(b: u8) -> bool {
+    b.is_power_of_two()
+}
+
+#[test]
+fn full_byte_mapping() {
+    const { assert!(REVERSE_BITS_COVERS_FULL_DOMAIN) };
+    for b in 0..=u8::MAX {
+        assert_eq!(reverse_bits(b), b.reverse_bits());
+    }
+}
+
+#[test]
+fn full_byte_predicate() {
+    const { assert!(IS_BASE64_COVERS_FULL_DOMAIN) };
+    for b in 0..=u8::MAX {
+        let expected = b.is_ascii_alphanumeric() || b == b'+' || b == b'/';
+        assert_eq!(is_base64(b), expected, "{b}");
+        let bits = is_base64_table();
+        assert_eq!(bits[b as usize / 8] >> (b % 8) & 1 != 0, expected);
+    }
+}
+
+#[test]
+fn full_byte_predicate_stores_only_bits() {
+    assert_eq!(size_of_val(is_base64_table()), 32);
+    assert_eq!(
+        size_of_val(&_mod_precalc_is_power_of_two::LOOKUP_TABLE_IS_POWER_OF_TWO_BITS),
+        32
+    );
+}
+
+#[test]
+fn partial_byte_predicate_stores_bools() {
+    assert_eq!(size_of_val(is_even_table()), 255);
+    assert!(is_even(254));
+    assert!(!is_even(255));
+}
+
+#[test]
+fn full_signed_byte_predicate() {
+    for b in i8::MIN..=i8::MAX {
+        assert_eq!(is_odd(b), b % 2 != 0);
+    }
+}
+
+#[test]
+fn full_byte_predicate_in_option_mode() {
+    for b in 0..=u8::MAX {
+        assert_eq!(is_power_of_two(b), Some(b.is_power_of_two()));
+    }
+}
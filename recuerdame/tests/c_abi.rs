@@ -0,0 +1,20 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = 0..=4, c_abi = "recuerdame_test_add_lookup")]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[precalculate(a = 0..=255, panic, c_abi = "recuerdame_test_square_lookup")]
+const fn square(a: u8) -> u16 {
+    a as u16 * a as u16
+}
+
+#[test]
+fn c_abi_wrapper_uses_table() {
+    let lookup: extern "C" fn(i32, i32) -> i32 = recuerdame_test_add_lookup;
+    assert_eq!(lookup(5, 2), 7);
+    // Fallback mode still evaluates out of range inputs.
+    assert_eq!(lookup(20, 0), 20);
+    assert_eq!(recuerdame_test_square_lookup(16), 256);
+}
@@ -0,0 +1,54 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = 0..=4, cfg = test, option)]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[precalculate(x = 0..=10, cfg = not(feature = "serde"), export)]
+const fn double(x: u8) -> u8 {
+    x * 2
+}
+
+#[precalculate(x = 0..=10, cfg = feature = "serde", export)]
+const fn double(x: u8) -> u8 {
+    x + x
+}
+
+// `any()` never holds, so nothing is generated and `triple` is free to be
+// defined again.
+#[precalculate(x = 0..=10, cfg = any())]
+const fn triple(x: u8) -> u8 {
+    x * 3
+}
+
+const fn triple(x: u8) -> u8 {
+    x + x + x
+}
+
+struct Scaler;
+
+impl Scaler {
+    #[precalculate(n = 0..=10, self_value = Scaler, cfg = any())]
+    const fn scale(&self, n: u8) -> u8 {
+        n * 4
+    }
+
+    const fn scale(&self, n: u8) -> u8 {
+        n << 2
+    }
+}
+
+#[test]
+fn enabled_cfg_generates_the_function() {
+    assert_eq!(add(3, 4), Some(7));
+    assert_eq!(add(11, 0), None);
+    assert_eq!(double(5), 10);
+    assert_eq!(double_table().len(), 11);
+}
+
+#[test]
+fn disabled_cfg_generates_nothing() {
+    assert_eq!(triple(5), 15);
+    assert_eq!(Scaler.scale(3), 12);
+}
@@ -0,0 +1,62 @@
+use recuerdame::precalculate;
+
+// `char::from` is not callable in constants yet, `char::from_u32` is.
+#[precalculate(c = char::from_u32(0x20).unwrap()..=char::from_u32(0x7E).unwrap(), option)]
+const fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation()
+}
+
+const FIRST_LETTER: u8 = b'a';
+
+#[precalculate(c = (FIRST_LETTER as char)..=((FIRST_LETTER + 25) as char), panic)]
+const fn alphabet_position(c: char) -> u8 {
+    c as u8 - FIRST_LETTER + 1
+}
+
+#[precalculate(c = 'a'..='f', n = 0..=3)]
+const fn shift(c: char, n: u8) -> char {
+    (c as u8 + n) as char
+}
+
+// Spans the surrogate code points, which are not `char`s.
+#[precalculate(c = '\u{D7FF}'..='\u{E000}', option)]
+const fn code_point(c: char) -> u32 {
+    c as u32
+}
+
+#[test]
+fn computed_char_bounds_over_printable_ascii() {
+    assert_eq!(IS_PUNCTUATION_DIMS, [95]);
+    for c in ' '..='~' {
+        assert_eq!(is_punctuation(c), Some(c.is_ascii_punctuation()), "{c:?}");
+    }
+    assert_eq!(is_punctuation('\n'), None);
+    assert_eq!(is_punctuation('\u{7f}'), None);
+    assert_eq!(is_punctuation('é'), None);
+}
+
+#[test]
+fn char_bounds_from_const_casts() {
+    assert_eq!(alphabet_position('a'), 1);
+    assert_eq!(alphabet_position('z'), 26);
+}
+
+#[test]
+#[should_panic(expected = "argument `c` is out of the precalculated range")]
+fn out_of_range_char_panics() {
+    alphabet_position('A');
+}
+
+#[test]
+fn char_with_integer_arguments() {
+    assert_eq!(shift('a', 3), 'd');
+    assert_eq!(shift('z', 1), '{');
+    assert_eq!(shift_unindex(5), ('b', 1));
+}
+
+#[test]
+fn ranges_spanning_surrogates() {
+    assert_eq!(code_point('\u{D7FF}'), Some(0xD7FF));
+    assert_eq!(code_point('\u{E000}'), Some(0xE000));
+    assert_eq!(code_point('\u{E001}'), None);
+}
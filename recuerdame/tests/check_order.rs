@@ -0,0 +1,43 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = 0..=4, option)]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[precalculate(a = 0..=10, b = 0..=4, option, check_order = [b, a])]
+const fn add_reordered(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[precalculate(a = 0..=10, b = 0..=4, panic, check_order = [b, a])]
+const fn add_checked_b_first(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[precalculate(a = 0..=10, b = 0..=4, c = -3..=3, check_order = [c])]
+const fn add_3_reordered(a: i32, b: i32, c: i32) -> i32 {
+    a + b + c
+}
+
+#[test]
+fn check_order_does_not_change_results() {
+    (-5..=15).for_each(|a| {
+        (-5..=10).for_each(|b| assert_eq!(add_reordered(a, b), add(a, b)))
+    });
+}
+
+#[test]
+fn partial_check_order_keeps_fallback() {
+    (-5..=15).for_each(|a| {
+        (-5..=10).for_each(|b| {
+            (-6..=6).for_each(|c| assert_eq!(add_3_reordered(a, b, c), a + b + c))
+        })
+    });
+}
+
+#[test]
+#[should_panic(expected = "argument `b` = 9 is out of the precalculated range")]
+fn check_order_sets_the_reported_argument() {
+    add_checked_b_first(20, 9);
+}
@@ -0,0 +1,64 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = -4..=4, export)]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[precalculate(c = b'a'..=b'z', panic)]
+const fn to_upper(c: u8) -> u8 {
+    c.to_ascii_uppercase()
+}
+
+#[precalculate(a = 0..=10, b = 0..=4, option)]
+const fn mul(a: i32, b: i32) -> i32 {
+    a * b
+}
+
+#[test]
+fn checked_returns_indices_in_range() {
+    assert_eq!(add_checked(0, -4), Some((0, 0)));
+    assert_eq!(add_checked(3, 2), Some((3, 6)));
+    assert_eq!(add_checked(10, 4), Some((10, 8)));
+    assert_eq!(to_upper_checked(b'c'), Some((2,)));
+}
+
+#[test]
+fn checked_returns_none_out_of_range() {
+    assert_eq!(add_checked(11, 0), None);
+    assert_eq!(add_checked(-1, 0), None);
+    assert_eq!(add_checked(0, 5), None);
+    assert_eq!(add_checked(0, -5), None);
+    assert_eq!(to_upper_checked(b'A'), None);
+}
+
+#[test]
+fn checked_indices_index_the_table() {
+    let table = add_table();
+    (0..=10).for_each(|a| {
+        (-4..=4).for_each(|b| {
+            let (ia, ib) = add_checked(a, b).unwrap();
+            assert_eq!(table[ia][ib], add(a, b));
+        })
+    });
+}
+
+#[test]
+fn by_index_reads_the_table() {
+    assert_eq!(add_by_index(3, 6), add(3, 2));
+    (0..=10).for_each(|a| {
+        (-4..=4).for_each(|b| {
+            let (ia, ib) = add_checked(a, b).unwrap();
+            assert_eq!(add_by_index(ia, ib), add(a, b));
+        })
+    });
+    assert_eq!(to_upper_by_index(2), b'C');
+}
+
+#[test]
+fn by_index_out_of_the_table() {
+    assert_eq!(mul_by_index(3, 2), mul(3, 2));
+    assert_eq!(mul_by_index(11, 0), None);
+    assert_eq!(mul_by_index(0, 5), None);
+    assert!(std::panic::catch_unwind(|| add_by_index(11, 0)).is_err());
+}
@@ -0,0 +1,52 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = clamp(0..=10), b = 0..=4, option)]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[precalculate(a = clamp(-5..=5), b = 0..=4, variants(panic))]
+const fn mul(a: i8, b: i8) -> i8 {
+    a * b
+}
+
+#[precalculate(x = clamp(0..=100) by 10)]
+const fn tenth(x: u8) -> u8 {
+    x / 10
+}
+
+#[precalculate(x = clamp(1..=3), small = match)]
+const fn triple(x: u8) -> u8 {
+    x * 3
+}
+
+#[test]
+fn clamped_axis_and_option_axis() {
+    assert_eq!(add(5, 2), Some(7));
+    // `a` is clamped into 0..=10.
+    assert_eq!(add(-3, 2), Some(2));
+    assert_eq!(add(20, 4), Some(14));
+    // `b` is out of range and returns `None`.
+    assert_eq!(add(5, 5), None);
+    assert_eq!(add(20, -1), None);
+    // `add_checked` reports the table membership without clamping.
+    assert_eq!(add_checked(20, 4), None);
+}
+
+#[test]
+fn clamped_axis_in_fallback_and_panic() {
+    assert_eq!(mul(100, 2), 10);
+    // Out of range `b` falls back to the original function.
+    assert_eq!(mul(-100, 10), -50);
+    assert_eq!(mul_panic(-100, 4), -20);
+    assert!(std::panic::catch_unwind(|| mul_panic(0, 5)).is_err());
+}
+
+#[test]
+fn clamped_stepped_and_small_match() {
+    assert_eq!(tenth(200), 10);
+    assert_eq!(tenth(55), 5);
+    assert_eq!(triple(0), 3);
+    assert_eq!(triple(2), 6);
+    assert_eq!(triple(9), 9);
+}
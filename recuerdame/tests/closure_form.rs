@@ -0,0 +1,31 @@
+use recuerdame::{precalculate, precalculate_closure};
+
+precalculate_closure!(double_closure, |x: u8| -> u16 { x as u16 * 2 }, x = 0..=255);
+
+#[precalculate(x = 0..=255)]
+const fn double_attr(x: u8) -> u16 {
+    x as u16 * 2
+}
+
+precalculate_closure!(
+    add_closure,
+    |a: i32, b: i32| -> i32 { a + b },
+    a = 0..=10,
+    b = 0..=4,
+    option
+);
+
+#[test]
+fn closure_form_matches_attribute_form() {
+    assert_eq!(
+        _mod_precalc_double_closure::LOOKUP_TABLE_DOUBLE_CLOSURE,
+        _mod_precalc_double_attr::LOOKUP_TABLE_DOUBLE_ATTR
+    );
+    (0..=255).for_each(|x| assert_eq!(double_closure(x), double_attr(x)));
+}
+
+#[test]
+fn closure_form_accepts_options() {
+    assert_eq!(add_closure(5, 2), Some(7));
+    assert_eq!(add_closure(20, 0), None);
+}
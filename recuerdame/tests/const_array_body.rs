@@ -0,0 +1,34 @@
+use recuerdame::precalculate;
+
+const DATA: [u32; 256] = {
+    let mut data = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        data[i] = (i as u32).wrapping_mul(2_654_435_761) >> 7;
+        i += 1;
+    }
+    data
+};
+
+#[precalculate(i = 0..=255, export)]
+const fn lookup(i: u8) -> u32 {
+    DATA[i as usize]
+}
+
+#[precalculate(i = 0..=255, storage = static, export)]
+const fn lookup_static(i: u8) -> u32 {
+    DATA[i as usize]
+}
+
+#[test]
+fn table_equals_the_backing_array() {
+    assert_eq!(lookup_table(), &DATA);
+    assert_eq!(lookup_static_table(), &DATA);
+}
+
+#[test]
+fn lookups_match_the_backing_array() {
+    for i in 0..=255u8 {
+        assert_eq!(lookup(i), DATA[i as usize]);
+    }
+}
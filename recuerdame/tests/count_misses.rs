@@ -0,0 +1,52 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = 0..=4, count_misses)]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[precalculate(x = 0..=10, option, count_misses, variants(panic))]
+const fn double(x: u8) -> u8 {
+    x * 2
+}
+
+#[precalculate(x = 1..=3, small = match, count_misses)]
+const fn triple(x: u8) -> u8 {
+    x * 3
+}
+
+struct Scaler;
+
+impl Scaler {
+    #[precalculate(n = 0..=10, self_value = Scaler, count_misses)]
+    const fn scale(&self, n: u8) -> u8 {
+        n * 4
+    }
+}
+
+#[test]
+fn fallback_counts_out_of_range_calls() {
+    assert_eq!(add_miss_count(), 0);
+    assert_eq!(add(3, 4), 7);
+    assert_eq!(add_miss_count(), 0);
+    assert_eq!(add(20, 4), 24);
+    assert_eq!(add(3, -1), 2);
+    assert_eq!(add_miss_count(), 2);
+}
+
+#[test]
+fn option_counts_out_of_range_calls() {
+    assert_eq!(double(11), None);
+    assert_eq!(double(5), Some(10));
+    assert_eq!(double_panic(5), 10);
+    assert_eq!(double_miss_count(), 1);
+}
+
+#[test]
+fn small_match_and_methods_count_out_of_range_calls() {
+    assert_eq!(triple(4), 12);
+    assert_eq!(triple(2), 6);
+    assert_eq!(triple_miss_count(), 1);
+    assert_eq!(Scaler.scale(20), 80);
+    assert_eq!(Scaler.scale(2), 8);
+}
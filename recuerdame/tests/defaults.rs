@@ -0,0 +1,27 @@
+use core::cmp::Ordering;
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = -4..=4, c = 0..=1, defaults(b = 2), defaults(b = -3, c = 1))]
+const fn add(a: i32, b: i32, c: i32) -> i32 {
+    a + b + c
+}
+
+#[precalculate(n = 0..=9, o = Ordering::Less..=Ordering::Greater, flag = false..=true, option, defaults(o = Ordering::Less), defaults(flag = true))]
+const fn pick(n: u8, o: Ordering, flag: bool) -> i16 {
+    let n = if flag { n as i16 } else { -(n as i16) };
+    n * o as i16
+}
+
+#[test]
+fn defaults_fix_arguments() {
+    assert_eq!(add_b2(3, 1), 6);
+    assert_eq!(add_bneg3_c1(5), 3);
+    assert_eq!(add_b2(30, 0), 32);
+}
+
+#[test]
+fn defaults_keep_the_operating_mode() {
+    assert_eq!(pick_o_less(4, true), Some(-4));
+    assert_eq!(pick_flag_true(4, Ordering::Greater), Some(4));
+    assert_eq!(pick_flag_true(10, Ordering::Greater), None);
+}
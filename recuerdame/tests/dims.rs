@@ -0,0 +1,96 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = 0..=4)]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[precalculate(x = 0..=100 by 25, flag = false..=true, y = -3..3)]
+const fn mix(x: u32, flag: bool, y: i8) -> i64 {
+    if flag { x as i64 * y as i64 } else { 0 }
+}
+
+#[test]
+fn dims_of_add() {
+    assert_eq!(ADD_DIMS, &[11, 5]);
+}
+
+#[test]
+fn dims_follow_argument_order() {
+    assert_eq!(MIX_DIMS, &[5, 2, 6]);
+    assert_eq!(MIX_DIMS.iter().product::<usize>(), 60);
+}
+
+/// Row-major offset of the indices returned by `<name>_checked`.
+fn flat_index(indices: &[usize], dims: &[usize]) -> usize {
+    indices.iter().zip(dims).fold(0, |flat, (index, dim)| flat * dim + index)
+}
+
+#[test]
+fn unindex_round_trips_add() {
+    for a in 0..=10 {
+        for b in 0..=4 {
+            let (ia, ib) = add_checked(a, b).unwrap();
+            assert_eq!(add_unindex(flat_index(&[ia, ib], ADD_DIMS)), (a, b));
+        }
+    }
+    assert_eq!(add_unindex(0), (0, 0));
+    assert_eq!(add_unindex(54), (10, 4));
+}
+
+#[test]
+fn unindex_round_trips_mixed_dimensions() {
+    for flat in 0..MIX_DIMS.iter().product() {
+        let (x, flag, y) = mix_unindex(flat);
+        let (ix, iflag, iy) = mix_checked(x, flag, y).unwrap();
+        assert_eq!(flat_index(&[ix, iflag, iy], MIX_DIMS), flat);
+    }
+    assert_eq!(mix_unindex(59), (100, true, 2));
+}
+
+#[test]
+#[should_panic(expected = "flat index is out of the precalculated table")]
+fn unindex_out_of_table_panics() {
+    add_unindex(55);
+}
+
+#[precalculate(a = 0..=1, b = 0..=2, c = 0..=1, export)]
+const fn order(a: u8, b: u8, c: u8) -> u8 {
+    a * 100 + b * 10 + c
+}
+
+/// Pins the row-major order of the table: the first argument is the
+/// outermost dimension and the last one varies fastest.
+#[test]
+fn flat_order_is_row_major() {
+    const EXPECTED: [u8; 12] = [0, 1, 10, 11, 20, 21, 100, 101, 110, 111, 120, 121];
+    let flattened = order_table().iter().flatten().flatten().copied().collect::<Vec<_>>();
+    assert_eq!(flattened, EXPECTED);
+    for (flat, value) in EXPECTED.into_iter().enumerate() {
+        let (a, b, c) = order_unindex(flat);
+        assert_eq!(a * 100 + b * 10 + c, value);
+    }
+}
+
+#[test]
+fn index_of_matches_manual_offset() {
+    for a in 0..=10 {
+        for b in 0..=4 {
+            assert_eq!(add_index_of(a, b), Some(a as usize * 5 + b as usize));
+        }
+    }
+    assert_eq!(add_index_of(11, 0), None);
+    assert_eq!(add_index_of(0, -1), None);
+}
+
+#[test]
+fn index_of_mixed_dimensions() {
+    // x = 0..=100 by 25, flag = false..=true, y = -3..3
+    assert_eq!(mix_index_of(50, true, -1), Some((2 * 2 + 1) * 6 + 2));
+    assert_eq!(mix_index_of(60, true, 0), Some((2 * 2 + 1) * 6 + 3));
+    assert_eq!(mix_index_of(50, true, 3), None);
+    for flat in 0..60 {
+        let (x, flag, y) = mix_unindex(flat);
+        assert_eq!(mix_index_of(x, flag, y), Some(flat));
+    }
+}
@@ -0,0 +1,80 @@
+use core::cmp::Ordering;
+use recuerdame::precalculate;
+
+#[precalculate(o = Ordering::Less..=Ordering::Greater, n = 0..=255)]
+const fn pick(o: Ordering, n: u8) -> u8 {
+    match o {
+        Ordering::Less => n / 2,
+        Ordering::Equal => n,
+        Ordering::Greater => n.saturating_mul(2),
+    }
+}
+
+#[precalculate(o = Ordering::Equal..=Ordering::Greater, n = 0..=10, option)]
+const fn pick_partial(o: Ordering, n: u8) -> u8 {
+    match o {
+        Ordering::Less => 0,
+        Ordering::Equal => n,
+        Ordering::Greater => n + 100,
+    }
+}
+
+const ORDERINGS: [Ordering; 3] = [Ordering::Less, Ordering::Equal, Ordering::Greater];
+
+#[test]
+fn equivalence_pick() {
+    ORDERINGS.iter().for_each(|o| {
+        (0..=255).for_each(|n| assert_eq!(pick(*o, n), _mod_precalc_pick::_pick_original(*o, n)))
+    });
+}
+
+#[test]
+fn ordering_indexes_the_table() {
+    assert_eq!(pick_checked(Ordering::Less, 7), Some((0, 7)));
+    assert_eq!(pick_checked(Ordering::Equal, 7), Some((1, 7)));
+    assert_eq!(pick_checked(Ordering::Greater, 7), Some((2, 7)));
+}
+
+#[test]
+fn ordering_sub_range() {
+    assert_eq!(pick_partial(Ordering::Less, 5), None);
+    assert_eq!(pick_partial(Ordering::Equal, 5), Some(5));
+    assert_eq!(pick_partial(Ordering::Greater, 5), Some(105));
+    assert_eq!(pick_partial(Ordering::Greater, 11), None);
+    assert_eq!(pick_partial_checked(Ordering::Equal, 5), Some((0, 5)));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl recuerdame::PrecalcEnum for Color {
+    const VARIANTS: &'static [Self] = &[Color::Red, Color::Green, Color::Blue];
+}
+
+#[precalculate(flag = false..=true, kind = Color::Red..=Color::Blue, n = 0..=15)]
+const fn encode(flag: bool, kind: Color, n: u8) -> u16 {
+    (flag as u16) << 8 | (kind as u16) << 5 | n as u16
+}
+
+#[test]
+fn mixed_dimensions() {
+    for flag in [false, true] {
+        for kind in [Color::Red, Color::Green, Color::Blue] {
+            for n in 0..=15 {
+                assert_eq!(
+                    encode(flag, kind, n),
+                    (flag as u16) << 8 | (kind as u16) << 5 | n as u16
+                );
+            }
+        }
+    }
+    assert_eq!(encode_checked(true, Color::Blue, 15), Some((1, 2, 15)));
+    assert_eq!(encode_checked(false, Color::Green, 16), None);
+    assert_eq!(ENCODE_DIMS, &[2, 3, 16]);
+    // Out of range integers fall back to the original function.
+    assert_eq!(encode(true, Color::Green, 20), 0x134);
+}
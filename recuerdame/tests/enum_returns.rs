@@ -0,0 +1,52 @@
+use recuerdame::{PrecalcConst, precalculate};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+enum Sign {
+    Negative,
+    Zero,
+    Positive,
+}
+
+impl PrecalcConst for Sign {
+    const DEFAULT: Self = Sign::Zero;
+}
+
+#[precalculate(n = -100..=100, export)]
+const fn sign(n: i32) -> Sign {
+    if n < 0 {
+        Sign::Negative
+    } else if n == 0 {
+        Sign::Zero
+    } else {
+        Sign::Positive
+    }
+}
+
+// The unused values of the byte are a niche, so the `Option` is a byte too.
+#[precalculate(n = 0..=255, export)]
+const fn odd_sign(n: u8) -> Option<Sign> {
+    match n % 2 {
+        0 => None,
+        _ => Some(Sign::Positive),
+    }
+}
+
+#[test]
+fn enum_table_stores_one_byte_per_entry() {
+    let table = sign_table();
+    assert_eq!(size_of_val(table), table.len());
+    assert_eq!(size_of_val(table), 201);
+    assert_eq!(sign(-7), Sign::Negative);
+    assert_eq!(sign(0), Sign::Zero);
+    assert_eq!(sign(42), Sign::Positive);
+    assert_eq!(sign(1000), Sign::Positive);
+}
+
+#[test]
+fn option_of_enum_uses_the_niche() {
+    let table = odd_sign_table();
+    assert_eq!(size_of_val(table), 256);
+    assert_eq!(odd_sign(3), Some(Sign::Positive));
+    assert_eq!(odd_sign(4), None);
+}
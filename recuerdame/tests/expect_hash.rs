@@ -0,0 +1,21 @@
+use recuerdame::precalculate;
+
+// The hashes are those of the little-endian bytes of the tables.
+#[cfg(target_endian = "little")]
+#[precalculate(a = 0..=10, b = 0..=4, expect_hash = "0x0cd8a7cbde5680de")]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[cfg(target_endian = "little")]
+#[precalculate(x = 0..=15, storage = static, expect_hash = "9d22444753854365")]
+const fn square(x: u16) -> u16 {
+    x * x
+}
+
+#[cfg(target_endian = "little")]
+#[test]
+fn matching_hash_builds() {
+    assert_eq!(add(3, 4), 7);
+    assert_eq!(square(15), 225);
+}
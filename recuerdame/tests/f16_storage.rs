@@ -0,0 +1,45 @@
+use recuerdame::precalculate;
+
+const STEPS: f32 = 256.0;
+
+#[precalculate(x = 0..=255, store = f16, export)]
+const fn smoothstep(x: u8) -> f32 {
+    let t = x as f32 / STEPS;
+    t * t * (3.0 - 2.0 * t)
+}
+
+#[precalculate(a = 0..=63, b = 0..=3, store = f16, storage = static, option)]
+const fn scaled(a: u8, b: u8) -> f32 {
+    a as f32 * 0.37 + b as f32 * 100.0
+}
+
+const fn smoothstep_exact(x: u8) -> f32 {
+    let t = x as f32 / STEPS;
+    t * t * (3.0 - 2.0 * t)
+}
+
+#[test]
+fn error_is_within_the_f16_precision() {
+    // Values in [0, 1) are rounded to 11 significant bits.
+    const { assert!(SMOOTHSTEP_STORE_ERROR > 0.0) };
+    const { assert!(SMOOTHSTEP_STORE_ERROR <= 1.0 / 4096.0) };
+    for x in 0..=u8::MAX {
+        let error = (smoothstep(x) - smoothstep_exact(x)).abs();
+        assert!(error <= SMOOTHSTEP_STORE_ERROR, "{x}");
+    }
+}
+
+#[test]
+fn table_holds_half_the_bytes() {
+    assert_eq!(core::mem::size_of_val(smoothstep_table()), 256 * 2);
+}
+
+#[test]
+fn error_grows_with_the_magnitude() {
+    // The largest value, 23.31 + 300, has 0.25 between two f16 values.
+    const { assert!(SCALED_STORE_ERROR <= 0.125) };
+    assert_eq!(scaled(0, 1), Some(100.0));
+    assert_eq!(scaled(64, 0), None);
+    let value = scaled(10, 2).unwrap();
+    assert!((value - 203.7).abs() <= SCALED_STORE_ERROR);
+}
@@ -0,0 +1,63 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=255, b = 0..=3)]
+const fn checked_add(a: u8, b: u8) -> Option<u8> {
+    a.checked_add(b)
+}
+
+#[precalculate(a = -5..=5)]
+const fn checked_div(a: i32) -> Option<i32> {
+    100_i32.checked_div(a)
+}
+
+const fn digit_value(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        _ => None,
+    }
+}
+
+// `?` is not allowed in a `const fn` yet, `let ... else` is its const equivalent.
+#[precalculate(c = b'0'..=b'9', option)]
+const fn parse_digit(c: u8) -> Option<u8> {
+    let Some(value) = digit_value(c) else {
+        return None;
+    };
+    value.checked_mul(2)
+}
+
+#[test]
+fn overflowing_inputs_are_stored_as_none() {
+    assert_eq!(checked_add(255, 1), None);
+    assert_eq!(checked_add(254, 3), None);
+    assert_eq!(checked_add(254, 1), Some(255));
+    assert_eq!(checked_add(0, 0), Some(0));
+}
+
+#[test]
+fn single_failing_input_is_stored_as_none() {
+    assert_eq!(checked_div(0), None);
+    (-5..=5).filter(|a| *a != 0).for_each(|a| {
+        assert_eq!(checked_div(a), Some(100 / a));
+    });
+}
+
+#[test]
+fn equivalence_checked_add() {
+    (0..=255).for_each(|a| {
+        (0..=3).for_each(|b| {
+            assert_eq!(
+                checked_add(a, b),
+                _mod_precalc_checked_add::_checked_add_original(a, b)
+            )
+        })
+    });
+}
+
+#[test]
+fn option_mode_nests_fallible_results() {
+    // The outer Option reports the range check, the inner one the function.
+    assert_eq!(parse_digit(b'7'), Some(Some(14)));
+    assert_eq!(parse_digit(b'0'), Some(Some(0)));
+    assert_eq!(parse_digit(b'a'), None);
+}
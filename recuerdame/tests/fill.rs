@@ -0,0 +1,38 @@
+use recuerdame::precalculate;
+
+/// Doesn't implement `PrecalcConst`, so the table needs a fill value.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    const fn diagonal(n: i32) -> Self {
+        Point { x: n, y: n }
+    }
+}
+
+const ORIGIN_OFFSET: i32 = 3;
+
+#[precalculate(n = -5..=5, fill = const { Point::diagonal(ORIGIN_OFFSET * 2) })]
+const fn mirror(n: i32) -> Point {
+    Point { x: n, y: -n }
+}
+
+#[precalculate(n = 0..=3, option, fill = Point { x: 0, y: 0 })]
+const fn scale(n: i32) -> Point {
+    Point { x: n * 10, y: n * 100 }
+}
+
+#[test]
+fn computed_fill_value_initializes_table() {
+    (-5..=5).for_each(|n| assert_eq!(mirror(n), Point { x: n, y: -n }));
+    assert_eq!(mirror(10), Point { x: 10, y: -10 });
+}
+
+#[test]
+fn fill_value_accepts_plain_expressions() {
+    assert_eq!(scale(2), Some(Point { x: 20, y: 200 }));
+    assert_eq!(scale(4), None);
+}
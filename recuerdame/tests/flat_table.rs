@@ -0,0 +1,46 @@
+use recuerdame::flat::FlatTable;
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=1, b = 0..=2, flat_table)]
+const fn digits(a: u8, b: u8) -> u8 {
+    a * 10 + b
+}
+
+#[precalculate(a = -2..=2, b = 0..=1, c = 0..=3, storage = heap, flat_table)]
+const fn product(a: i32, b: i32, c: i32) -> i32 {
+    a * b * c
+}
+
+#[test]
+fn flat_table_is_row_major() {
+    let table = digits_flat_table();
+    assert_eq!(table.dims(), &[2, 3]);
+    assert_eq!(table.values(), &[0, 1, 2, 10, 11, 12]);
+    assert_eq!(
+        serde_json::to_string(&table).unwrap(),
+        r#"{"dims":[2,3],"values":[0,1,2,10,11,12]}"#
+    );
+}
+
+#[test]
+fn flat_table_round_trip() {
+    let json = serde_json::to_string(&product_flat_table()).unwrap();
+    let table: FlatTable<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(table, product_flat_table());
+    for a in -2..=2 {
+        for b in 0..=1 {
+            for c in 0..=3 {
+                let (ia, ib, ic) = product_checked(a, b, c).unwrap();
+                assert_eq!(table.get(&[ia, ib, ic]), Some(&product(a, b, c)));
+            }
+        }
+    }
+    assert_eq!(table.get(&[5, 0, 0]), None);
+    assert_eq!(table.get(&[0, 0]), None);
+}
+
+#[test]
+fn deserializing_rejects_mismatched_dimensions() {
+    let result = serde_json::from_str::<FlatTable<u8>>(r#"{"dims":[2,2],"values":[1,2,3]}"#);
+    assert!(result.is_err());
+}
@@ -0,0 +1,49 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = 0..=4, option)]
+const fn nested_div(a: u8, b: u8) -> Option<u8> {
+    a.checked_div(b)
+}
+
+#[precalculate(a = 0..=10, b = 0..=4, option, flatten_option)]
+const fn flat_div(a: u8, b: u8) -> Option<u8> {
+    a.checked_div(b)
+}
+
+#[precalculate(a = 0..=10, b = 0..=4, variants(option, panic), flatten_option)]
+const fn div(a: u8, b: u8) -> Option<u8> {
+    a.checked_div(b)
+}
+
+#[precalculate(x = 1..=3, small = match, option, flatten_option)]
+const fn halve_even(x: u8) -> Option<u8> {
+    if x.is_multiple_of(2) { Some(x / 2) } else { None }
+}
+
+#[test]
+fn option_mode_nests_by_default() {
+    assert_eq!(nested_div(8, 2), Some(Some(4)));
+    assert_eq!(nested_div(8, 0), Some(None));
+    assert_eq!(nested_div(20, 2), None);
+}
+
+#[test]
+fn flatten_option_merges_out_of_range_into_none() {
+    assert_eq!(flat_div(8, 2), Some(4));
+    assert_eq!(flat_div(8, 0), None);
+    assert_eq!(flat_div(20, 2), None);
+}
+
+#[test]
+fn flatten_option_applies_to_option_variant_only() {
+    assert_eq!(div_opt(9, 3), Some(3));
+    assert_eq!(div_opt(11, 3), None);
+    assert_eq!(div_panic(9, 0), None);
+}
+
+#[test]
+fn flatten_option_with_small_match() {
+    assert_eq!(halve_even(2), Some(1));
+    assert_eq!(halve_even(3), None);
+    assert_eq!(halve_even(4), None);
+}
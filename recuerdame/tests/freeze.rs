@@ -0,0 +1,117 @@
+use recuerdame::freeze::FrozenTable;
+use recuerdame::precalculate;
+
+const fn weighted(a: i32, b: i16) -> i64 {
+    a as i64 * 3 - b as i64
+}
+
+fn frozen_source() -> String {
+    FrozenTable::new("weighted_frozen", "i64")
+        .arg("a", "i32", -2, 3)
+        .arg("b", "i16", 1, 4)
+        .generate(|args| weighted(args[0] as i32, args[1] as i16))
+}
+
+#[precalculate(a = -2..=3, b = 1..=4, option)]
+const fn weighted_macro(a: i32, b: i16) -> i64 {
+    weighted(a, b)
+}
+
+fn wide_source() -> String {
+    FrozenTable::new("halve_frozen", "i8")
+        .arg("a", "i8", -100, 100)
+        .generate(|args| (args[0] / 2) as i8)
+}
+
+mod frozen {
+    include!("frozen/weighted.rs");
+}
+
+mod wide {
+    include!("frozen/halve.rs");
+}
+
+#[test]
+fn frozen_file_is_up_to_date() {
+    let files = [
+        (
+            concat!(env!("CARGO_MANIFEST_DIR"), "/tests/frozen/weighted.rs"),
+            frozen_source(),
+        ),
+        (
+            concat!(env!("CARGO_MANIFEST_DIR"), "/tests/frozen/halve.rs"),
+            wide_source(),
+        ),
+    ];
+    for (path, source) in files {
+        if std::env::var_os("RECUERDAME_FREEZE").is_some() {
+            std::fs::write(path, &source).unwrap();
+        }
+        assert_eq!(std::fs::read_to_string(path).unwrap(), source);
+    }
+}
+
+#[test]
+fn wide_signed_ranges_index_without_overflow() {
+    for a in i8::MIN..=i8::MAX {
+        let expected = (-100..=100).contains(&a).then_some(a / 2);
+        assert_eq!(wide::halve_frozen(a), expected, "{a}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "a frozen table needs at least one argument")]
+fn tables_without_arguments_are_rejected() {
+    FrozenTable::new("empty", "u8").generate(|_| 0u8);
+}
+
+#[test]
+fn frozen_table_matches_macro() {
+    for a in -5..=6 {
+        for b in -1..=6 {
+            assert_eq!(frozen::weighted_frozen(a, b), weighted_macro(a, b));
+        }
+    }
+}
+
+#[test]
+fn cached_table_is_only_generated_when_the_key_changes() {
+    let path = concat!(env!("CARGO_TARGET_TMPDIR"), "/weighted_cached.rs");
+    let _ = std::fs::remove_file(path);
+    let calls = std::cell::Cell::new(0);
+    let table = |max_b: i128, body: &str| {
+        FrozenTable::new("weighted_frozen", "i64")
+            .arg("a", "i32", -2, 3)
+            .arg("b", "i16", 1, max_b)
+            .key(body)
+    };
+    let f = |args: &[i128]| {
+        calls.set(calls.get() + 1);
+        weighted(args[0] as i32, args[1] as i16)
+    };
+
+    assert!(table(4, "a * 3 - b").write_cached(path, f).unwrap());
+    assert_eq!(calls.get(), 24);
+    let written = std::fs::read_to_string(path).unwrap();
+    assert!(written.ends_with(&frozen_source()));
+
+    // A second build finds the file up to date.
+    assert!(!table(4, "a * 3 - b").write_cached(path, f).unwrap());
+    assert_eq!(calls.get(), 24);
+    assert_eq!(std::fs::read_to_string(path).unwrap(), written);
+
+    // Changing a range or the body invalidates it.
+    assert!(table(5, "a * 3 - b").write_cached(path, f).unwrap());
+    assert_eq!(calls.get(), 24 + 30);
+    assert!(table(5, "a * 4 - b").write_cached(path, f).unwrap());
+    assert_eq!(calls.get(), 24 + 30 + 30);
+}
+
+#[test]
+#[should_panic(expected = "a cached frozen table needs a key")]
+fn cached_tables_without_a_key_are_rejected() {
+    let path = concat!(env!("CARGO_TARGET_TMPDIR"), "/unkeyed_cached.rs");
+    let _ = FrozenTable::new("unkeyed", "u8")
+        .arg("a", "u8", 0, 3)
+        .write_cached(path, |args| args[0] as u8);
+}
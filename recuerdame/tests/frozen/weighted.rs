@@ -0,0 +1,17 @@
+// @generated by recuerdame::freeze, do not edit.
+
+const LOOKUP_TABLE_WEIGHTED_FROZEN: [[i64; 4]; 6] = [
+    [-7, -8, -9, -10],
+    [-4, -5, -6, -7],
+    [-1, -2, -3, -4],
+    [2, 1, 0, -1],
+    [5, 4, 3, 2],
+    [8, 7, 6, 5],
+];
+
+pub const fn weighted_frozen(a: i32, b: i16) -> Option<i64> {
+    if !(-2 <= a && a <= 3 && 1 <= b && b <= 4) {
+        return None;
+    }
+    Some(LOOKUP_TABLE_WEIGHTED_FROZEN[(a as i128 - -2) as usize][(b as i128 - 1) as usize])
+}
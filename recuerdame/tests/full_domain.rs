@@ -0,0 +1,44 @@
+use core::cmp::Ordering;
+use recuerdame::precalculate;
+
+#[precalculate(x = 0..=255)]
+const fn full_byte(x: u8) -> u16 {
+    x as u16 * 3
+}
+
+#[precalculate(x = 0..=200)]
+const fn partial_byte(x: u8) -> u16 {
+    x as u16 * 3
+}
+
+#[precalculate(x = i8::MIN..=i8::MAX, flag = false..=true, option)]
+const fn full_signed(x: i8, flag: bool) -> i16 {
+    if flag { x as i16 } else { -(x as i16) }
+}
+
+#[precalculate(x = -128..=127, o = Ordering::Equal..=Ordering::Greater)]
+const fn partial_enum(x: i8, o: Ordering) -> i16 {
+    x as i16 * o as i16
+}
+
+#[test]
+fn full_u8_range_covers_domain() {
+    const { assert!(FULL_BYTE_COVERS_FULL_DOMAIN) };
+    const { assert!(FULL_SIGNED_COVERS_FULL_DOMAIN) };
+}
+
+#[test]
+fn partial_ranges_do_not_cover_domain() {
+    const { assert!(!PARTIAL_BYTE_COVERS_FULL_DOMAIN) };
+    const { assert!(!PARTIAL_ENUM_COVERS_FULL_DOMAIN) };
+}
+
+const _: () = assert!(FULL_BYTE_COVERS_FULL_DOMAIN);
+
+#[test]
+fn full_signed_range_lookups() {
+    for x in i8::MIN..=i8::MAX {
+        assert_eq!(full_signed(x, true), Some(x as i16));
+        assert_eq!(full_signed(x, false), Some(-(x as i16)));
+    }
+}
@@ -0,0 +1,39 @@
+use recuerdame::{fuse, precalculate};
+
+#[precalculate(x = -50..=50)]
+const fn f(x: i32) -> i32 {
+    x * x - 7
+}
+
+#[precalculate(y = -7..=2493, panic)]
+const fn g(y: i32) -> u16 {
+    (y.unsigned_abs() % 1000) as u16
+}
+
+const fn clamp_add(a: u8, b: u8) -> u8 {
+    a.saturating_add(b)
+}
+
+const fn is_even(n: u8) -> bool {
+    n.is_multiple_of(2)
+}
+
+fuse!(gf(x: i32) -> u16, g, f, x = -50..=50, option);
+fuse!(pub(crate) even_sum(a: u8, b: u8) -> bool, is_even, clamp_add, a = 0..=255, b = 0..=255);
+
+#[test]
+fn fused_table_matches_composition() {
+    for x in -50..=50 {
+        assert_eq!(gf(x), Some(g(f(x))));
+    }
+    assert_eq!(gf(51), None);
+}
+
+#[test]
+fn fused_function_of_several_arguments() {
+    for a in 0..=255 {
+        for b in 0..=255 {
+            assert_eq!(even_sum(a, b), is_even(clamp_add(a, b)));
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use recuerdame::precalculate;
+
+/// Stand-in for a numeric trait such as `num_traits::PrimInt`.
+trait PrimInt: Copy {
+    const WIDTH: u32;
+}
+
+impl PrimInt for i16 {
+    const WIDTH: u32 = 16;
+}
+
+impl PrimInt for i32 {
+    const WIDTH: u32 = 32;
+}
+
+#[precalculate(a = 0..=10, b = 0..=4, T = i32, name = add_i32)]
+#[precalculate(a = -5..=5, b = 0..=4, T = i16, name = add_i16, option)]
+const fn add<T: PrimInt>(a: T, b: T) -> T {
+    a + b
+}
+
+#[precalculate(x = 0..=8, T = i16, name = shift_i16)]
+#[precalculate(x = 0..=8, T = i32, name = shift_i32)]
+const fn shift<T>(x: u8) -> u32
+where
+    T: PrimInt,
+{
+    T::WIDTH - x as u32
+}
+
+#[derive(Clone, Copy)]
+enum Axis {
+    S,
+    T,
+}
+
+#[allow(non_snake_case)]
+struct Offsets {
+    T: u32,
+}
+
+// Only the type parameter is replaced, not the const, field or variant
+// sharing its name.
+#[precalculate(x = 0..=8, T = i16, name = offset_i16)]
+const fn offset<T: PrimInt>(x: u8) -> u32 {
+    const T: u32 = 100;
+    let offsets = Offsets { T: 20 };
+    let axis = if x % 2 == 0 { Axis::T } else { Axis::S };
+    let bonus = match axis {
+        Axis::T => offsets.T,
+        Axis::S => 0,
+    };
+    T + bonus + T::WIDTH + x as u32
+}
+
+#[test]
+fn instantiated_at_i32_and_i16() {
+    assert_eq!(add_i32(3, 4), 7i32);
+    assert_eq!(add_i32(100, 4), 104i32);
+    assert_eq!(add_i16(-5, 4), Some(-1i16));
+    assert_eq!(add_i16(6, 0), None);
+    for a in -5..=5 {
+        for b in 0..=4 {
+            assert_eq!(add_i16(a, b), Some(a + b));
+            assert_eq!(add_i32(a.into(), b.into()), i32::from(a + b));
+        }
+    }
+}
+
+#[test]
+fn type_parameter_only_in_the_body() {
+    assert_eq!(shift_i16(3), 13);
+    assert_eq!(shift_i32(3), 29);
+}
+
+#[test]
+fn other_items_named_like_the_parameter_are_kept() {
+    assert_eq!(offset_i16(2), 100 + 20 + 16 + 2);
+    assert_eq!(offset_i16(3), 100 + 16 + 3);
+}
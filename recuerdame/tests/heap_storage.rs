@@ -0,0 +1,43 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = -10..=10, b = 0..=4, storage = heap, export, variants(option))]
+const fn mul(a: i32, b: i32) -> i32 {
+    a * b
+}
+
+/// 20 MiB, above the default `max_bytes` that applies to embedded tables.
+#[precalculate(n = 0..=(5 << 20) - 1, storage = heap, panic)]
+const fn scramble(n: u32) -> u32 {
+    n.wrapping_mul(2_654_435_761).rotate_left(7)
+}
+
+const VALUES: [u8; 6] = [9, 8, 7, 6, 5, 4];
+
+#[precalculate(a = 0..=1, b = 0..=2, c = 0..=0, storage = heap, values = &VALUES, option)]
+const fn listed(a: u8, b: u8, c: u8) -> u8 {
+    a + b + c
+}
+
+#[test]
+fn heap_table_lookups() {
+    assert_eq!(mul(-10, 4), -40);
+    assert_eq!(mul(11, 2), 22);
+    assert_eq!(mul_opt(3, 3), Some(9));
+    assert_eq!(mul_opt(3, 5), None);
+    assert_eq!(mul_table()[0][4], -40);
+    assert!(std::ptr::eq(mul_table(), mul_table()));
+}
+
+#[test]
+fn large_heap_table() {
+    for n in [0, 1, 12345, (5 << 20) - 1] {
+        assert_eq!(scramble(n), n.wrapping_mul(2_654_435_761).rotate_left(7));
+    }
+}
+
+#[test]
+fn heap_table_from_values() {
+    assert_eq!(listed(0, 0, 0), Some(9));
+    assert_eq!(listed(1, 0, 0), Some(6));
+    assert_eq!(listed(1, 2, 0), Some(4));
+}
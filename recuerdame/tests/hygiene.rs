@@ -0,0 +1,25 @@
+use recuerdame::precalculate;
+
+// These names match the items the macro generates for an argument named `x`
+// and must not be shadowed by them.
+const X_MIN: i32 = -5;
+const X_MAX: i32 = 5;
+const X_SIZE: i32 = 1000;
+
+const fn generate_table() -> i32 {
+    7
+}
+
+#[precalculate(x = X_MIN..=X_MAX, option)]
+const fn offset(x: i32) -> i32 {
+    x * X_SIZE + generate_table()
+}
+
+#[test]
+fn user_names_are_not_shadowed_by_generated_items() {
+    assert_eq!(offset(-5), Some(-4993));
+    assert_eq!(offset(0), Some(7));
+    assert_eq!(offset(5), Some(5007));
+    assert_eq!(offset(6), None);
+    assert_eq!(offset(-6), None);
+}
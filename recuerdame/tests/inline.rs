@@ -0,0 +1,30 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = 0..=4, inline = never, panic, variants(option))]
+const fn add_outlined(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[precalculate(a = 0..=10, b = 0..=4, inline = always)]
+const fn add_inlined(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[test]
+fn inline_options_keep_results() {
+    for a in 0..=10 {
+        for b in 0..=4 {
+            assert_eq!(add_outlined(a, b), a + b);
+            assert_eq!(add_outlined_opt(a, b), Some(a + b));
+            assert_eq!(add_inlined(a, b), a + b);
+        }
+    }
+    assert_eq!(add_outlined_opt(11, 0), None);
+    assert_eq!(add_inlined(20, 9), 29);
+}
+
+#[test]
+fn inline_options_stay_const() {
+    const SUM: i32 = add_outlined(5, 2) + add_inlined(3, 4);
+    assert_eq!(SUM, 14);
+}
@@ -0,0 +1,51 @@
+use recuerdame::precalculate;
+
+type Byte = u8;
+type Signed = i8;
+
+#[precalculate(x = 0..=10)]
+const fn double(x: Byte) -> u16 {
+    x as u16 * 2
+}
+
+#[precalculate(x = -100..=100, option)]
+const fn halve(x: Signed) -> Signed {
+    x / 2
+}
+
+#[precalculate(x = -100..=100 by 25, option)]
+const fn quarter(x: Signed) -> i16 {
+    x as i16 * 4
+}
+
+#[precalculate(x = 1..=5, panic)]
+const fn triple(x: core::primitive::u8) -> u8 {
+    x * 3
+}
+
+#[test]
+fn aliases_of_integers_are_integer_arguments() {
+    assert_eq!(double(7), 14);
+    assert_eq!(double(200), 400);
+    assert_eq!(double_checked(10), Some((10,)));
+    assert_eq!(DOUBLE_DIMS, [11]);
+}
+
+#[test]
+fn signed_aliases_index_across_zero() {
+    for x in -100..=100 {
+        assert_eq!(halve(x), Some(x / 2));
+    }
+    assert_eq!(halve(101), None);
+    assert_eq!(halve_checked(100), Some((200,)));
+    assert_eq!(quarter(-100), Some(-400));
+    assert_eq!(quarter(100), Some(400));
+    assert_eq!(quarter(-60), Some(-300));
+    assert_eq!(quarter_checked(75), Some((7,)));
+}
+
+#[test]
+fn qualified_primitives_are_integer_arguments() {
+    assert_eq!(triple(5), 15);
+    assert_eq!(triple_checked(1), Some((0,)));
+}
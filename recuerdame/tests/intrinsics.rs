@@ -0,0 +1,45 @@
+use recuerdame::precalculate;
+
+#[precalculate(n = 0..=u16::MAX, panic)]
+const fn popcount(n: u16) -> u32 {
+    n.count_ones()
+}
+
+#[precalculate(n = 0..=u8::MAX, b = 0..=7)]
+const fn bit_stats(n: u8, b: u32) -> (u32, u32, u32, u8) {
+    (
+        n.leading_zeros(),
+        n.trailing_zeros(),
+        n.rotate_left(b).count_ones(),
+        n.reverse_bits(),
+    )
+}
+
+#[precalculate(x = -1000..=1000, option)]
+const fn magnitude(x: i16) -> (u16, i16, Option<u32>) {
+    (x.unsigned_abs(), x.signum(), x.unsigned_abs().checked_ilog2())
+}
+
+#[test]
+fn popcount_over_full_u16_range() {
+    for n in 0..=u16::MAX {
+        assert_eq!(popcount(n), n.count_ones());
+    }
+}
+
+#[test]
+fn bit_intrinsics() {
+    for n in 0..=u8::MAX {
+        for b in 0..=7 {
+            assert_eq!(bit_stats(n, b), _mod_precalc_bit_stats::_bit_stats_original(n, b));
+        }
+    }
+    assert_eq!(bit_stats(0b0001_0100, 1), (3, 2, 2, 0b0010_1000));
+}
+
+#[test]
+fn abs_and_log_intrinsics() {
+    assert_eq!(magnitude(-1000), Some((1000, -1, Some(9))));
+    assert_eq!(magnitude(0), Some((0, 0, None)));
+    assert_eq!(magnitude(1001), None);
+}
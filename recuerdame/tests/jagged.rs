@@ -0,0 +1,50 @@
+use recuerdame::precalculate;
+
+const PRIMES: [u16; 10] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29];
+
+/// The primes below `n`.
+#[precalculate(n = 0..=30, jagged, export)]
+const fn primes_below(n: u8) -> &'static [u16] {
+    let mut count = 0;
+    while count < PRIMES.len() && PRIMES[count] < n as u16 {
+        count += 1;
+    }
+    PRIMES.split_at(count).0
+}
+
+const WORDS: [&[u8]; 4] = [b"", b"a", b"bc", b"defg"];
+
+#[precalculate(a = 0..=3, b = false..=true, jagged, option, storage = static)]
+const fn word(a: u8, b: bool) -> &'static [u8] {
+    if b { WORDS[3 - a as usize] } else { WORDS[a as usize] }
+}
+
+#[test]
+fn rows_of_differing_lengths() {
+    assert_eq!(primes_below(0), &[] as &[u16]);
+    assert_eq!(primes_below(3), &[2]);
+    assert_eq!(primes_below(12), &[2, 3, 5, 7, 11]);
+    assert_eq!(primes_below(30), &PRIMES);
+    for n in 0..=30 {
+        assert_eq!(primes_below(n), _mod_precalc_primes_below::_primes_below_original(n));
+    }
+}
+
+#[test]
+fn table_holds_offsets_into_the_rows() {
+    let table = primes_below_table();
+    assert_eq!(table[0], (0, 0));
+    assert_eq!(table[3], (0, 1));
+    assert_eq!(table[4], (1, 3));
+    // Out of range inputs still fall back to the original function.
+    assert_eq!(primes_below(100), &PRIMES);
+}
+
+#[test]
+fn jagged_with_several_arguments() {
+    assert_eq!(word(0, false), Some(&b""[..]));
+    assert_eq!(word(3, false), Some(&b"defg"[..]));
+    assert_eq!(word(0, true), Some(&b"defg"[..]));
+    assert_eq!(word(2, true), Some(&b"a"[..]));
+    assert_eq!(word(4, true), None);
+}
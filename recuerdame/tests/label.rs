@@ -0,0 +1,24 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = 0..=4, label = "sum LUT v2")]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[precalculate(x = 0..=3, name = square_small, label = "small squares")]
+#[precalculate(x = 0..=15, name = square_big, label = "big squares")]
+const fn square(x: u16) -> u16 {
+    x * x
+}
+
+#[test]
+fn label_is_exported_next_to_the_function() {
+    assert_eq!(ADD_LABEL, "sum LUT v2");
+    assert_eq!(add(3, 4), 7);
+}
+
+#[test]
+fn stacked_tables_have_their_own_label() {
+    assert_eq!(SQUARE_SMALL_LABEL, "small squares");
+    assert_eq!(SQUARE_BIG_LABEL, "big squares");
+}
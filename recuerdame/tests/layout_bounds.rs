@@ -0,0 +1,32 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=(core::mem::size_of::<u64>() as u8), option, export)]
+const fn bits(a: u8) -> u32 {
+    a as u32 * 8
+}
+
+#[precalculate(
+    a = (core::mem::align_of::<u16>() as i32)..=(core::mem::size_of::<[u32; 4]>() as i32),
+    b = -(core::mem::size_of::<u16>() as i8)..=core::mem::size_of::<u16>() as i8,
+    option
+)]
+const fn shift(a: i32, b: i8) -> i32 {
+    a + b as i32
+}
+
+#[test]
+fn size_of_bounds_survive() {
+    assert_eq!(BITS_DIMS, [9]);
+    assert_eq!(bits(8), Some(64));
+    assert_eq!(bits(9), None);
+}
+
+#[test]
+fn align_of_and_negated_size_of_bounds() {
+    assert_eq!(SHIFT_DIMS, [15, 5]);
+    assert_eq!(shift(2, -2), Some(0));
+    assert_eq!(shift(16, 2), Some(18));
+    assert_eq!(shift(1, 0), None);
+    assert_eq!(shift(17, 0), None);
+    assert_eq!(shift(2, 3), None);
+}
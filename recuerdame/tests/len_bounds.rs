@@ -0,0 +1,31 @@
+use recuerdame::precalculate;
+
+const PRIMES: [u32; 10] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29];
+const WEIGHTS: [u16; 4] = [1, 10, 100, 1000];
+
+#[precalculate(i = 0..=(PRIMES.len() - 1), option)]
+const fn prime_squared(i: usize) -> u32 {
+    PRIMES[i] * PRIMES[i]
+}
+
+#[precalculate(i = 0..=(PRIMES.len() - 1), w = 0..=(WEIGHTS.len() - 1))]
+const fn weighted(i: usize, w: usize) -> u32 {
+    PRIMES[i] * WEIGHTS[w] as u32
+}
+
+#[test]
+fn table_is_sized_to_the_array() {
+    assert_eq!(PRIME_SQUARED_DIMS, [PRIMES.len()]);
+    assert_eq!(WEIGHTED_DIMS, [PRIMES.len(), WEIGHTS.len()]);
+    assert_eq!(prime_squared(PRIMES.len()), None);
+}
+
+#[test]
+fn table_matches_the_array_element_wise() {
+    for (i, prime) in PRIMES.iter().enumerate() {
+        assert_eq!(prime_squared(i), Some(prime * prime));
+        for (w, weight) in WEIGHTS.iter().enumerate() {
+            assert_eq!(weighted(i, w), prime * *weight as u32);
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use recuerdame::precalculate;
+
+#[precalculate(n = 0..=3, fill = "")]
+const fn name<'a>(n: u8) -> &'static str
+where
+    'a: 'a,
+{
+    match n {
+        0 => "zero",
+        1 => "one",
+        2 => "two",
+        _ => "many",
+    }
+}
+
+#[precalculate(n = 0..=3, option, fill = "")]
+const fn name_opt<'a, 'b: 'a>(n: u8) -> &'static str {
+    name(n)
+}
+
+#[test]
+fn benign_lifetime_parameters_are_preserved() {
+    assert_eq!(name(0), "zero");
+    assert_eq!(name(2), "two");
+    assert_eq!(name(200), "many");
+    assert_eq!(name_opt(1), Some("one"));
+    assert_eq!(name_opt(4), None);
+}
@@ -0,0 +1,25 @@
+use recuerdame::precalculate;
+
+#[test]
+fn precalculated_function_inside_a_function_body() {
+    #[precalculate(a = 0..=10, b = 0..=4, variants(option))]
+    const fn add(a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    assert_eq!(add(3, 4), 7);
+    assert_eq!(add(20, 4), 24);
+    assert_eq!(add_opt(20, 4), None);
+}
+
+const MAX: u8 = 9;
+
+#[test]
+fn local_item_uses_module_items() {
+    #[precalculate(d = 0..=MAX)]
+    const fn square(d: u8) -> u16 {
+        d as u16 * d as u16
+    }
+
+    assert_eq!(square(9), 81);
+}
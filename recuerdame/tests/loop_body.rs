@@ -0,0 +1,41 @@
+use recuerdame::precalculate;
+
+#[precalculate(n = 0..=255)]
+const fn sum_to(n: u8) -> u32 {
+    let mut total = 0;
+    let mut i = 0;
+    while i <= n as u32 {
+        total += i;
+        i += 1;
+    }
+    total
+}
+
+#[precalculate(n = 1..=255, option)]
+const fn collatz_steps(n: u8) -> u16 {
+    const LIMIT: u16 = 1000;
+    let mut x = n as u64;
+    let mut steps = 0;
+    while x != 1 && steps < LIMIT {
+        x = if x.is_multiple_of(2) { x / 2 } else { 3 * x + 1 };
+        steps += 1;
+    }
+    steps
+}
+
+#[test]
+fn looping_body_matches_the_closed_form() {
+    for n in 0..=255u8 {
+        let n32 = n as u32;
+        assert_eq!(sum_to(n), n32 * (n32 + 1) / 2);
+    }
+    const { assert!(SUM_TO_COVERS_FULL_DOMAIN) };
+}
+
+#[test]
+fn nested_const_items_and_loops() {
+    assert_eq!(collatz_steps(1), Some(0));
+    assert_eq!(collatz_steps(6), Some(8));
+    assert_eq!(collatz_steps(27), Some(111));
+    assert_eq!(collatz_steps(0), None);
+}
@@ -0,0 +1,47 @@
+use recuerdame::precalculate;
+
+#[derive(Clone, Copy)]
+struct Metric;
+
+#[derive(Clone, Copy)]
+struct Imperial;
+
+trait Units {
+    const CM_PER_UNIT: u32;
+}
+
+impl Units for Metric {
+    const CM_PER_UNIT: u32 = 100;
+}
+
+impl Units for Imperial {
+    const CM_PER_UNIT: u32 = 30;
+}
+
+#[precalculate(units = marker(Metric), n = 0..=200)]
+const fn to_cm(units: Metric, n: u8) -> u32 {
+    let _ = units;
+    n as u32 * Metric::CM_PER_UNIT
+}
+
+#[precalculate(n = 0..=10, _units = marker(Imperial), option)]
+const fn feet_to_cm(n: u8, _units: Imperial) -> u32 {
+    n as u32 * Imperial::CM_PER_UNIT
+}
+
+#[test]
+fn marker_is_forwarded_and_not_indexed() {
+    assert_eq!(TO_CM_DIMS, [1, 201]);
+    assert_eq!(to_cm(Metric, 3), 300);
+    assert_eq!(to_cm(Metric, 200), 20_000);
+    // Out of range inputs fall back to the original, with the marker.
+    assert_eq!(to_cm(Metric, 201), 20_100);
+    assert_eq!(to_cm_checked(Metric, 5), Some((0, 5)));
+    assert_eq!(to_cm_unindex(7).1, 7);
+}
+
+#[test]
+fn marker_after_the_ranged_argument() {
+    assert_eq!(feet_to_cm(2, Imperial), Some(60));
+    assert_eq!(feet_to_cm(11, Imperial), None);
+}
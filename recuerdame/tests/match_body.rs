@@ -0,0 +1,44 @@
+use recuerdame::precalculate;
+
+#[precalculate(n = 0..=255)]
+const fn category(n: u8) -> u8 {
+    match n {
+        0 => 0,
+        1..=9 => 1,
+        10 | 20 | 30 => 2,
+        x if x.is_multiple_of(7) => 3,
+        b'a'..=b'z' => 4,
+        x @ 100..=199 if x % 2 == 1 => 5,
+        200.. => 6,
+        _ => 7,
+    }
+}
+
+#[precalculate(a = -5..=5, b = false..=true)]
+const fn sign(a: i8, b: bool) -> i8 {
+    match (a.signum(), b) {
+        (0, _) => 0,
+        (s, true) => -s,
+        (s, false) => s,
+    }
+}
+
+#[test]
+fn match_with_guards_and_ranges() {
+    for n in 0..=255 {
+        assert_eq!(category(n), _mod_precalc_category::_category_original(n));
+    }
+    assert_eq!(category(14), 3);
+    assert_eq!(category(b'c'), 4);
+    assert_eq!(category(151), 5);
+    assert_eq!(category(250), 6);
+}
+
+#[test]
+fn match_on_tuple_of_arguments() {
+    for a in -5..=5 {
+        for b in [false, true] {
+            assert_eq!(sign(a, b), _mod_precalc_sign::_sign_original(a, b));
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=255, max_bytes = 256)]
+const fn identity(a: u8) -> u8 {
+    a
+}
+
+const BUDGET: usize = 11 * 5 * 4;
+
+#[precalculate(a = 0..=10, b = 0..=4, max_bytes = BUDGET)]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[test]
+fn table_at_exactly_max_bytes_compiles() {
+    assert_eq!(identity(200), 200);
+    assert_eq!(
+        std::mem::size_of_val(_mod_precalc_identity::LOOKUP_TABLE_IDENTITY),
+        256
+    );
+    assert_eq!(add(3, 4), 7);
+    assert_eq!(std::mem::size_of_val(_mod_precalc_add::LOOKUP_TABLE_ADD), BUDGET);
+}
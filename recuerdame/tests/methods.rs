@@ -0,0 +1,58 @@
+use recuerdame::precalculate;
+
+pub struct Scaler {
+    factor: u8,
+    offset: u8,
+}
+
+const SCALER: Scaler = Scaler {
+    factor: 3,
+    offset: 7,
+};
+
+impl Scaler {
+    #[precalculate(n = 0..=50, self_value = SCALER)]
+    pub const fn scale(&self, n: u8) -> u8 {
+        n * self.factor + self.offset
+    }
+
+    #[precalculate(n = 0..=10, self_value = SCALER, option)]
+    const fn scale_small(&self, n: u8) -> u16 {
+        n as u16 * self.factor as u16
+    }
+
+    #[precalculate(n = 0..=10, self_value = Scaler { factor: 2, offset: 0 }, panic)]
+    const fn double(&self, n: u8) -> u8 {
+        n * self.factor
+    }
+}
+
+#[test]
+fn method_uses_table_built_with_self_value() {
+    assert_eq!(SCALER.scale(0), 7);
+    assert_eq!(SCALER.scale(50), 157);
+    // The table ignores the runtime `self`.
+    let other = Scaler {
+        factor: 1,
+        offset: 0,
+    };
+    assert_eq!(other.scale(10), 37);
+}
+
+#[test]
+fn method_fallback_calls_original_with_self_value() {
+    assert_eq!(SCALER.scale(60), 187);
+}
+
+#[test]
+fn method_modes() {
+    assert_eq!(SCALER.scale_small(10), Some(30));
+    assert_eq!(SCALER.scale_small(11), None);
+    assert_eq!(SCALER.double(10), 20);
+}
+
+#[test]
+#[should_panic(expected = "argument `n` = 11 is out of the precalculated range")]
+fn method_panic_mode() {
+    SCALER.double(11);
+}
@@ -0,0 +1,37 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 250..=255, b = -300..=-290, c = 70_000..=70_003, option, export)]
+const fn mix(a: u8, b: i16, c: u32) -> i64 {
+    a as i64 * 1_000_000 + b as i64 * 1_000 + c as i64
+}
+
+#[test]
+fn each_dimension_uses_its_own_type() {
+    assert_eq!(MIX_DIMS, [6, 11, 4]);
+    assert_eq!(mix_table().len(), 6);
+    assert_eq!(mix_table()[0].len(), 11);
+    assert_eq!(mix_table()[0][0].len(), 4);
+}
+
+#[test]
+fn mixed_widths_match_the_original() {
+    for a in 250..=255u8 {
+        for b in -300..=-290i16 {
+            for c in 70_000..=70_003u32 {
+                assert_eq!(
+                    mix(a, b, c),
+                    Some(_mod_precalc_mix::_mix_original(a, b, c))
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn mixed_widths_out_of_range() {
+    assert_eq!(mix(249, -295, 70_000), None);
+    assert_eq!(mix(255, -289, 70_000), None);
+    assert_eq!(mix(255, -301, 70_000), None);
+    assert_eq!(mix(255, -295, 69_999), None);
+    assert_eq!(mix(255, -295, 70_004), None);
+}
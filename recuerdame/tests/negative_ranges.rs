@@ -0,0 +1,58 @@
+use recuerdame::precalculate;
+
+const LOW: i16 = -300;
+
+#[precalculate(a = -20..=-10, export)]
+const fn inclusive_i8(a: i8) -> i8 {
+    -a
+}
+
+#[precalculate(a = -20..-10, export, option)]
+const fn exclusive_i8(a: i8) -> i8 {
+    -a
+}
+
+#[precalculate(a = LOW..-250, b = -3..0, export, option)]
+const fn exclusive_i16(a: i16, b: i16) -> i16 {
+    a * b
+}
+
+#[precalculate(a = -1_000_000..-999_990, small = match, option)]
+const fn exclusive_i32(a: i32) -> i32 {
+    a + 1_000_000
+}
+
+#[test]
+fn negative_inclusive_range() {
+    assert_eq!(inclusive_i8_table().len(), 11);
+    assert_eq!(inclusive_i8_checked(-20), Some((0,)));
+    assert_eq!(inclusive_i8_checked(-10), Some((10,)));
+    assert_eq!(inclusive_i8(-15), 15);
+}
+
+#[test]
+fn negative_exclusive_range() {
+    assert_eq!(exclusive_i8_table().len(), 10);
+    assert_eq!(exclusive_i8_checked(-20), Some((0,)));
+    assert_eq!(exclusive_i8_checked(-11), Some((9,)));
+    assert_eq!(exclusive_i8(-11), Some(11));
+    assert_eq!(exclusive_i8(-10), None);
+    assert_eq!(exclusive_i8(-21), None);
+}
+
+#[test]
+fn negative_exclusive_range_with_non_literal_start() {
+    assert_eq!(exclusive_i16_table().len(), 50);
+    assert_eq!(exclusive_i16_table()[0].len(), 3);
+    assert_eq!(exclusive_i16(-300, -3), Some(900));
+    assert_eq!(exclusive_i16(-251, -1), Some(251));
+    assert_eq!(exclusive_i16(-250, -1), None);
+    assert_eq!(exclusive_i16(-300, 0), None);
+}
+
+#[test]
+fn negative_exclusive_range_small_match() {
+    assert_eq!(exclusive_i32(-1_000_000), Some(0));
+    assert_eq!(exclusive_i32(-999_991), Some(9));
+    assert_eq!(exclusive_i32(-999_990), None);
+}
@@ -0,0 +1,52 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = -2..=4, opaque_table)]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[precalculate(x = 0..=15, opaque_table, storage = static)]
+const fn square_root(x: u8) -> u8 {
+    let mut root = 0;
+    while (root + 1) * (root + 1) <= x {
+        root += 1;
+    }
+    root
+}
+
+#[test]
+fn get_matches_the_function_in_range() {
+    for a in 0..=10 {
+        for b in -2..=4 {
+            assert_eq!(add_table().get(a, b), Some(add(a, b)));
+        }
+    }
+}
+
+#[test]
+fn get_rejects_out_of_range_arguments() {
+    assert_eq!(add_table().get(11, 0), None);
+    assert_eq!(add_table().get(0, -3), None);
+    assert_eq!(add_table().get(-1, 5), None);
+}
+
+#[test]
+fn dims_match_the_ranges() {
+    let table: &'static AddTable = add_table();
+    assert_eq!(table.dims(), &[11, 7]);
+    assert_eq!(table.dims(), ADD_DIMS);
+}
+
+#[test]
+fn static_tables_can_be_opaque() {
+    assert_eq!(square_root_table().get(9), Some(3));
+    assert_eq!(square_root_table().get(16), None);
+    assert_eq!(SquareRootTable::dims(square_root_table()), &[16]);
+}
+
+const ADD_3_2: Option<i32> = add_table().get(3, 2);
+
+#[test]
+fn get_is_usable_in_const_contexts() {
+    assert_eq!(ADD_3_2, Some(5));
+}
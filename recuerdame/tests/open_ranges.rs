@@ -0,0 +1,49 @@
+use recuerdame::precalculate;
+
+// 64 bytes of `u16` entries, 5 per value of `a`: `a` gets 32 / 5 = 6 values.
+#[precalculate(a = 0.., b = 0..=4, max_bytes = 64)]
+const fn add(a: u16, b: u16) -> u16 {
+    a + b
+}
+
+#[precalculate(x = -10.., max_bytes = 100, option)]
+const fn square(x: i32) -> i32 {
+    x * x
+}
+
+// The budget is larger than the type, the range ends at `u8::MAX`.
+#[precalculate(x = 250.., max_bytes = 1024, option)]
+const fn half(x: u8) -> u8 {
+    x / 2
+}
+
+#[test]
+fn open_range_fits_the_budget() {
+    assert_eq!(ADD_DIMS, [6, 5]);
+    assert!(6 * 5 * size_of::<u16>() <= 64);
+    for a in 0..=5 {
+        assert_eq!(add_checked(a, 4), Some((a as usize, 4)));
+    }
+    assert_eq!(add_checked(6, 0), None);
+}
+
+#[test]
+fn out_of_the_open_range_uses_the_original() {
+    assert_eq!(add(5, 4), 9);
+    assert_eq!(add(100, 4), 104);
+}
+
+#[test]
+fn open_range_with_a_negative_start() {
+    assert_eq!(SQUARE_DIMS, [25]);
+    assert_eq!(square(-10), Some(100));
+    assert_eq!(square(14), Some(196));
+    assert_eq!(square(15), None);
+}
+
+#[test]
+fn open_range_capped_at_the_type_max() {
+    assert_eq!(HALF_DIMS, [6]);
+    assert_eq!(half(255), Some(127));
+    assert_eq!(half(249), None);
+}
@@ -0,0 +1,51 @@
+use recuerdame::precalculate;
+
+#[precalculate(i = 0..=15, b = 0..=1, packed, export)]
+const fn row(i: u8, b: u8) -> [u8; 4] {
+    [i, i.wrapping_mul(3), b, i ^ b]
+}
+
+#[precalculate(x = 0..=9, storage = static, packed)]
+const fn pair(x: u16) -> [u16; 2] {
+    [x, x * x]
+}
+
+#[test]
+fn packed_table_is_flat() {
+    assert_eq!(ROW_PACKED.len(), 16 * 2 * 4);
+    assert_eq!(&ROW_PACKED[..8], &[0, 0, 0, 0, 0, 0, 1, 1]);
+    let flattened = row_table()
+        .iter()
+        .flatten()
+        .flatten()
+        .copied()
+        .collect::<Vec<_>>();
+    assert_eq!(ROW_PACKED, flattened);
+}
+
+#[test]
+fn packed_lookup_returns_entry_slice() {
+    for i in 0..=15 {
+        for b in 0..=1 {
+            assert_eq!(row_packed(i, b), Some(&row(i, b)[..]));
+        }
+    }
+    assert_eq!(row_packed(16, 0), None);
+    assert_eq!(pair_packed(7), Some(&[7, 49][..]));
+    assert_eq!(PAIR_PACKED.len(), 20);
+}
+
+#[precalculate(x = 0..=9, packed)]
+const fn even(x: u8) -> bool {
+    x % 2 == 0
+}
+
+#[test]
+fn bit_tables_by_index_stop_at_the_table() {
+    assert!(even_by_index(8));
+    assert!(!even_by_index(9));
+    // Indices 10 to 15 are the padding bits of the last byte.
+    assert!(std::panic::catch_unwind(|| even_by_index(10)).is_err());
+    assert!(std::panic::catch_unwind(|| even_by_index(12)).is_err());
+    assert!(std::panic::catch_unwind(|| even_by_index(20)).is_err());
+}
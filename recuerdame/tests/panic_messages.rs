@@ -0,0 +1,41 @@
+use recuerdame::precalculate;
+use std::cmp::Ordering;
+
+#[precalculate(a = 0..=10, b = -4..=4, panic)]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[precalculate(x = 0..=200, panic)]
+const fn double(x: u64) -> u64 {
+    x * 2
+}
+
+#[precalculate(o = Ordering::Equal..=Ordering::Greater, panic)]
+const fn sign(o: Ordering) -> i8 {
+    o as i8
+}
+
+#[test]
+#[should_panic(expected = "argument `a` = 11 is out of the precalculated range")]
+fn names_the_first_argument() {
+    add(11, 0);
+}
+
+#[test]
+#[should_panic(expected = "argument `b` = -5 is out of the precalculated range")]
+fn names_negative_values() {
+    add(0, -5);
+}
+
+#[test]
+#[should_panic(expected = "argument `x` = 18446744073709551615 is out of the precalculated range")]
+fn formats_large_values() {
+    double(u64::MAX);
+}
+
+#[test]
+#[should_panic(expected = "argument `o` is out of the precalculated range")]
+fn names_enum_arguments() {
+    sign(Ordering::Less);
+}
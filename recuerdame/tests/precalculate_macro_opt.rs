@@ -45,6 +45,12 @@ const fn const_range_test(i: u32) -> u32 {
     i * i
 }
 
+const RANGE: std::ops::RangeInclusive<u32> = START..=END;
+#[precalculate(i = RANGE, option)]
+const fn range_const_test(i: u32) -> u32 {
+    i * i
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -140,6 +146,18 @@ mod test {
         });
     }
 
+    #[test]
+    fn equivalence_range_const_test() {
+        RANGE.for_each(|i| {
+            assert_eq!(
+                range_const_test(i),
+                Some(_mod_precalc_range_const_test::_range_const_test_original(i))
+            )
+        });
+        assert_eq!(range_const_test(START - 1), None);
+        assert_eq!(range_const_test(END + 1), None);
+    }
+
     #[test]
     fn add_is_none_when_first_arg_is_out_of_bounds_upper() {
         assert_eq!(add(11, 0), None);
@@ -0,0 +1,74 @@
+use recuerdame::precalculate_struct;
+
+const IDLE: u8 = 0;
+const RUNNING: u8 = 1;
+const DONE: u8 = 2;
+
+precalculate_struct! {
+    /// Transitions of a small state machine.
+    #[derive(Debug, Clone, Copy)]
+    pub struct Machine {
+        #[precalculate(state = IDLE..=DONE, input = 0..=3, panic)]
+        pub const fn next(state: u8, input: u8) -> u8 {
+            match (state, input) {
+                (IDLE, 1) => RUNNING,
+                (RUNNING, 2) => DONE,
+                (DONE, 3) => IDLE,
+                _ => state,
+            }
+        }
+
+        #[precalculate(state = IDLE..=DONE, option)]
+        pub const fn is_final(state: u8) -> bool {
+            state == DONE
+        }
+
+        #[precalculate(state = IDLE..=DONE)]
+        const fn label(state: u8) -> char {
+            match state {
+                IDLE => 'i',
+                RUNNING => 'r',
+                DONE => 'd',
+                _ => '?',
+            }
+        }
+    }
+}
+
+impl Machine {
+    fn trace(self, inputs: &[u8]) -> String {
+        let mut state = IDLE;
+        let mut trace = String::from(self.label(state));
+        for &input in inputs {
+            state = self.next(state, input);
+            trace.push(self.label(state));
+        }
+        trace
+    }
+}
+
+const FINAL: Option<bool> = Machine.is_final(DONE);
+
+#[test]
+fn methods_bundle_the_lookups() {
+    let machine = Machine;
+    assert_eq!(machine.next(IDLE, 1), RUNNING);
+    assert_eq!(machine.next(RUNNING, 0), RUNNING);
+    assert_eq!(machine.next(RUNNING, 2), DONE);
+    assert_eq!(machine.is_final(DONE), Some(true));
+    assert_eq!(machine.is_final(IDLE), Some(false));
+    assert_eq!(machine.is_final(7), None);
+    assert_eq!(FINAL, Some(true));
+}
+
+#[test]
+fn private_methods_are_usable_in_the_module() {
+    assert_eq!(Machine.trace(&[1, 0, 2, 3]), "irrdi");
+    assert_eq!(Machine.label(9), '?');
+}
+
+#[test]
+#[should_panic(expected = "argument `input` = 4 is out of the precalculated range")]
+fn modes_apply_per_method() {
+    Machine.next(IDLE, 4);
+}
@@ -0,0 +1,71 @@
+use core::num::Wrapping;
+
+use recuerdame::precalculate;
+
+#[precalculate(d = 0..=15, option)]
+const fn hex_digit(d: u8) -> char {
+    match d {
+        0..=9 => (b'0' + d) as char,
+        _ => (b'a' + d - 10) as char,
+    }
+}
+
+#[precalculate(n = 0..=100)]
+const fn is_prime(n: u32) -> bool {
+    if n < 2 {
+        return false;
+    }
+    let mut i = 2;
+    while i * i <= n {
+        if n.is_multiple_of(i) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+type Score = u32;
+
+#[precalculate(hits = 0..=10, misses = 0..=10, value_stats)]
+const fn score(hits: u8, misses: u8) -> Score {
+    (hits as Score * 10).saturating_sub(misses as Score * 3)
+}
+
+#[test]
+fn char_return_works() {
+    assert_eq!(hex_digit(0), Some('0'));
+    assert_eq!(hex_digit(9), Some('9'));
+    assert_eq!(hex_digit(10), Some('a'));
+    assert_eq!(hex_digit(15), Some('f'));
+    assert_eq!(hex_digit(16), None);
+}
+
+#[test]
+fn bool_return_works() {
+    let primes = (0..=100).filter(|n| is_prime(*n)).collect::<Vec<_>>();
+    assert_eq!(primes.len(), 25);
+    assert_eq!(&primes[..5], &[2, 3, 5, 7, 11]);
+    (0..=100).for_each(|n| assert_eq!(is_prime(n), _mod_precalc_is_prime::_is_prime_original(n)));
+}
+
+#[test]
+fn type_alias_return_works() {
+    assert_eq!(score(10, 0), 100);
+    assert_eq!(score(2, 10), 0);
+    assert_eq!(score(11, 1), 107);
+    assert_eq!((SCORE_VALUE_MIN, SCORE_VALUE_MAX), (0, 100));
+}
+
+#[precalculate(a = 250..=255, b = 0..=10, option)]
+const fn wrapping_add(a: u8, b: u8) -> Wrapping<u8> {
+    Wrapping(a.wrapping_add(b))
+}
+
+#[test]
+fn wrapping_returns() {
+    assert_eq!(wrapping_add(250, 5), Some(Wrapping(255)));
+    assert_eq!(wrapping_add(255, 10), Some(Wrapping(9)));
+    assert_eq!(wrapping_add(249, 0), None);
+    assert_eq!(wrapping_add(255, 1).map(|sum| sum + Wrapping(1)), Some(Wrapping(1)));
+}
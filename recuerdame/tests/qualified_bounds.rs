@@ -0,0 +1,91 @@
+use recuerdame::precalculate;
+
+trait Bounded {
+    const MIN_IDX: Self;
+    const MAX_IDX: Self;
+}
+
+impl Bounded for u8 {
+    const MIN_IDX: u8 = 2;
+    const MAX_IDX: u8 = 12;
+}
+
+impl Bounded for i16 {
+    const MIN_IDX: i16 = -30;
+    const MAX_IDX: i16 = 30;
+}
+
+#[precalculate(a = <u8 as Bounded>::MIN_IDX..=<u8 as Bounded>::MAX_IDX, option)]
+const fn double(a: u8) -> u8 {
+    a * 2
+}
+
+#[precalculate(
+    a = <u8 as Bounded>::MIN_IDX..=<u8 as Bounded>::MAX_IDX,
+    b = <i16 as Bounded>::MIN_IDX..=<i16 as Bounded>::MAX_IDX by 10,
+    option
+)]
+const fn mul(a: u8, b: i16) -> i16 {
+    a as i16 * b
+}
+
+#[test]
+fn qualified_path_bounds() {
+    assert_eq!(double(2), Some(4));
+    assert_eq!(double(12), Some(24));
+    assert_eq!(double(1), None);
+    assert_eq!(double(13), None);
+}
+
+#[test]
+fn qualified_path_bounds_with_step() {
+    assert_eq!(mul(3, -30), Some(-90));
+    assert_eq!(mul(3, 10), Some(30));
+    assert_eq!(mul(3, 31), None);
+}
+
+trait Grid {
+    const RANGES: [(i64, i64); 2];
+}
+
+struct Small;
+
+impl Grid for Small {
+    const RANGES: [(i64, i64); 2] = [(0, 3), (-1, 1)];
+}
+
+#[precalculate(ranges_const = <Small as Grid>::RANGES, fill = <i32 as recuerdame::PrecalcConst>::DEFAULT, option)]
+const fn sub(a: i32, b: i32) -> i32 {
+    a - b
+}
+
+#[test]
+fn qualified_path_ranges_const_and_fill() {
+    assert_eq!(sub(3, -1), Some(4));
+    assert_eq!(sub(4, 0), None);
+}
+
+const WIDE_MIN: i32 = -40;
+const WIDE_MAX: i32 = 40;
+
+#[precalculate(a = (WIDE_MIN as i16)..=(WIDE_MAX as i16), option)]
+const fn negate(a: i16) -> i16 {
+    -a
+}
+
+#[precalculate(a = WIDE_MIN as i16 / 2..WIDE_MAX as i16 / 2, b = 0..=(WIDE_MAX as u8 / 10))]
+const fn scale(a: i16, b: u8) -> i16 {
+    a * b as i16
+}
+
+#[test]
+fn cast_bounds() {
+    assert_eq!(negate(-40), Some(40));
+    assert_eq!(negate(40), Some(-40));
+    assert_eq!(negate(41), None);
+    assert_eq!(negate(-41), None);
+    assert_eq!(SCALE_DIMS, &[40, 5]);
+    assert_eq!(scale(-20, 4), -80);
+    assert_eq!(scale_checked(19, 4), Some((39, 4)));
+    assert_eq!(scale_checked(20, 4), None);
+}
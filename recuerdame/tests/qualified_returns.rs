@@ -0,0 +1,55 @@
+use recuerdame::precalculate;
+
+mod net {
+    use recuerdame::PrecalcConst;
+
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    pub struct Ipv4(pub [u8; 4]);
+
+    impl PrecalcConst for Ipv4 {
+        const DEFAULT: Self = Ipv4([0; 4]);
+    }
+
+    pub trait Address {
+        type Repr;
+    }
+
+    impl Address for Ipv4 {
+        type Repr = u32;
+    }
+}
+
+type Host = net::Ipv4;
+
+#[precalculate(host = 0..=255, option)]
+const fn local(host: u8) -> crate::net::Ipv4 {
+    net::Ipv4([192, 168, 0, host])
+}
+
+#[precalculate(host = 0..=255, variants(option, panic))]
+const fn loopback(host: u8) -> self::Host {
+    net::Ipv4([127, 0, 0, host])
+}
+
+#[precalculate(host = 0..=255, option)]
+const fn local_repr(host: u8) -> <net::Ipv4 as net::Address>::Repr {
+    u32::from_be_bytes([192, 168, 0, host])
+}
+
+#[test]
+fn qualified_return_type_in_option_mode() {
+    assert_eq!(local(7), Some(net::Ipv4([192, 168, 0, 7])));
+    assert_eq!(local_checked(7), Some((7,)));
+}
+
+#[test]
+fn aliased_return_type() {
+    assert_eq!(loopback(1), net::Ipv4([127, 0, 0, 1]));
+    assert_eq!(loopback_opt(1), Some(net::Ipv4([127, 0, 0, 1])));
+    assert_eq!(loopback_panic(255), net::Ipv4([127, 0, 0, 255]));
+}
+
+#[test]
+fn associated_type_return() {
+    assert_eq!(local_repr(1), Some(0xc0a8_0001));
+}
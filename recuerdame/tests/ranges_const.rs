@@ -0,0 +1,32 @@
+use recuerdame::precalculate;
+
+const RANGES: [(i32, i32); 2] = [(0, 10), (-4, 4)];
+
+#[precalculate(ranges_const = RANGES, option)]
+const fn add(a: i32, b: i16) -> i32 {
+    a + b as i32
+}
+
+#[precalculate(ranges_const = RANGES, b = 0..=1, option)]
+const fn add_override(a: i32, b: i16) -> i32 {
+    a + b as i32
+}
+
+#[test]
+fn ranges_come_from_const_array() {
+    (0..=10).for_each(|a| {
+        (-4..=4).for_each(|b| assert_eq!(add(a, b), Some(a + b as i32)))
+    });
+    assert_eq!(add(-1, 0), None);
+    assert_eq!(add(11, 0), None);
+    assert_eq!(add(0, 5), None);
+    assert_eq!(add(0, -5), None);
+    assert_eq!(add_checked(3, -4), Some((3, 0)));
+}
+
+#[test]
+fn explicit_range_takes_precedence() {
+    assert_eq!(add_override(10, 1), Some(11));
+    assert_eq!(add_override(10, 2), None);
+    assert_eq!(add_override(11, 0), None);
+}
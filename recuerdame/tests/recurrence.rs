@@ -0,0 +1,51 @@
+use recuerdame::precalculate;
+
+// Without `recurrence`, evaluating `fib(90)` by recursion would not finish.
+#[precalculate(n = 0..=90, recurrence(depends_on = [n - 1, n - 2]), option)]
+const fn fib(n: u8) -> u64 {
+    if n < 2 { n as u64 } else { fib(n - 1) + fib(n - 2) }
+}
+
+// The entries below the table are computed by recursion.
+#[precalculate(n = 20..=60, recurrence(depends_on = [n - 1, n - 2]))]
+const fn fib_from_20(n: u8) -> u64 {
+    if n < 2 { n as u64 } else { fib_from_20(n - 1) + fib_from_20(n - 2) }
+}
+
+#[precalculate(n = -10..=100, recurrence(depends_on = [n - 1]))]
+const fn steps(n: i16) -> i64 {
+    if n <= -10 { 0 } else { steps(n - 1) + n as i64 }
+}
+
+fn fib_iterative(n: u8) -> u64 {
+    let (mut a, mut b) = (0u64, 1u64);
+    for _ in 0..n {
+        (a, b) = (b, a + b);
+    }
+    a
+}
+
+#[test]
+fn fibonacci_matches() {
+    for n in 0..=90 {
+        assert_eq!(fib(n), Some(fib_iterative(n)));
+    }
+    assert_eq!(fib(90), Some(2_880_067_194_370_816_120));
+    assert_eq!(fib(91), None);
+}
+
+#[test]
+fn recurrence_starting_above_the_base_case() {
+    for n in 20..=60 {
+        assert_eq!(fib_from_20(n), fib_iterative(n));
+    }
+    assert_eq!(fib_from_20(10), 55);
+}
+
+#[test]
+fn recurrence_over_negative_inputs() {
+    assert_eq!(steps(-10), 0);
+    assert_eq!(steps(-9), -9);
+    assert_eq!(steps(100), (-9..=100).sum::<i64>());
+    assert_eq!(steps(101), (-9..=101).sum::<i64>());
+}
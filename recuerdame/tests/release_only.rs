@@ -0,0 +1,78 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = -20..=20, b = 0..=9, release_only, export)]
+const fn poly(a: i32, b: i32) -> i32 {
+    a * a * b - 3 * a + b
+}
+
+#[precalculate(a = 0..=100 by 10, release_only, option, variants(panic))]
+const fn tenth(a: u8) -> u8 {
+    a / 10
+}
+
+// The body panics for `x == 5`, so evaluating the table fails to compile.
+// Debug builds never evaluate it, whatever reads it in release builds.
+#[cfg(debug_assertions)]
+#[precalculate(x = 0..=10, release_only, export, value_stats, expect_hash = "0x0")]
+const fn fragile(x: u8) -> u8 {
+    if x == 5 {
+        panic!("fragile can't be precalculated");
+    }
+    x
+}
+
+#[cfg(debug_assertions)]
+#[precalculate(x = 0..=255, release_only, storage = static, opaque_table)]
+const fn fragile_bits(x: u8) -> bool {
+    if x == 5 {
+        panic!("fragile_bits can't be precalculated");
+    }
+    x % 2 == 0
+}
+
+/// The function reads the table in release builds and calls the original in
+/// debug builds, `_by_index` is compared to both in either build.
+#[test]
+fn both_paths_return_identical_values() {
+    for a in -20..=20 {
+        for b in 0..=9 {
+            let (ia, ib) = poly_checked(a, b).unwrap();
+            assert_eq!(poly(a, b), poly_by_index(ia, ib));
+            assert_eq!(poly(a, b), _mod_precalc_poly::_poly_original(a, b));
+        }
+    }
+    assert_eq!(poly(21, 1), _mod_precalc_poly::_poly_original(21, 1));
+}
+
+#[cfg(not(debug_assertions))]
+#[test]
+fn release_builds_export_the_table() {
+    let (ia, ib) = poly_checked(-3, 7).unwrap();
+    assert_eq!(poly_table()[ia][ib], poly(-3, 7));
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn debug_builds_never_evaluate_the_table() {
+    assert_eq!(fragile(4), 4);
+    assert_eq!(fragile_by_index(10), 10);
+    assert!(std::panic::catch_unwind(|| fragile(5)).is_err());
+    assert!(std::panic::catch_unwind(|| fragile_by_index(11)).is_err());
+    assert!(fragile_bits(200));
+    assert!(!fragile_bits_by_index(201));
+}
+
+#[test]
+fn release_only_keeps_mode_semantics() {
+    assert_eq!(tenth(50), Some(5));
+    assert_eq!(tenth(101), None);
+    assert_eq!(tenth_panic(100), 10);
+    assert!(std::panic::catch_unwind(|| tenth_panic(101)).is_err());
+}
+
+#[test]
+fn debug_builds_never_use_the_table() {
+    assert_eq!(poly_used_table(3, 4), !cfg!(debug_assertions));
+    assert_eq!(tenth_used_table(50), !cfg!(debug_assertions));
+    assert!(!poly_used_table(21, 1));
+}
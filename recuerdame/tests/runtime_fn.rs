@@ -0,0 +1,33 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = 0..=4, rt)]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[precalculate(x = -8..=8, option, rt)]
+const fn cube(x: i64) -> i64 {
+    x * x * x
+}
+
+const SUM: i32 = add(7, 3);
+const CUBE: Option<i64> = cube(-2);
+
+#[test]
+fn const_and_runtime_functions_agree() {
+    for a in -2..=12 {
+        for b in -2..=6 {
+            assert_eq!(add(a, b), add_rt(a, b));
+        }
+    }
+    for x in -10..=10 {
+        assert_eq!(cube(x), cube_rt(x));
+    }
+    assert_eq!(cube_rt(9), None);
+}
+
+#[test]
+fn const_function_is_usable_in_constants() {
+    assert_eq!(SUM, 10);
+    assert_eq!(CUBE, Some(-8));
+}
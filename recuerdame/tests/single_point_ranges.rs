@@ -0,0 +1,43 @@
+use recuerdame::precalculate;
+
+const BASE: i32 = 40;
+const OFFSET: i32 = 2;
+
+#[precalculate(a = (BASE + OFFSET)..=(BASE + OFFSET), option)]
+const fn answer(a: i32) -> i32 {
+    a * 10
+}
+
+#[precalculate(a = 0..=10, b = (BASE - OFFSET * 20)..=(BASE - OFFSET * 20), option)]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[precalculate(a = 7..=7, option)]
+const fn seven(a: u8) -> u8 {
+    a
+}
+
+#[test]
+fn expression_bounds_give_a_single_entry() {
+    assert_eq!(ANSWER_DIMS, [1]);
+    assert_eq!(answer(42), Some(420));
+    assert_eq!(answer(41), None);
+    assert_eq!(answer(43), None);
+    assert_eq!(answer_unindex(0), (42,));
+}
+
+#[test]
+fn single_point_dimension_next_to_a_range() {
+    assert_eq!(ADD_DIMS, [11, 1]);
+    assert_eq!(add(3, 0), Some(3));
+    assert_eq!(add(3, 1), None);
+    assert_eq!(add(11, 0), None);
+}
+
+#[test]
+fn literal_single_point() {
+    assert_eq!(SEVEN_DIMS, [1]);
+    assert_eq!(seven(7), Some(7));
+    assert_eq!(seven(6), None);
+}
@@ -0,0 +1,37 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=2, b = -1..=1, small = match)]
+const fn mix_match(a: i32, b: i32) -> i32 {
+    a * 10 + b
+}
+
+#[precalculate(a = 0..=2, b = -1..=1)]
+const fn mix_array(a: i32, b: i32) -> i32 {
+    a * 10 + b
+}
+
+#[precalculate(c = b'x'..=b'z', small = match, option, variants(panic))]
+const fn axis(c: u8) -> u8 {
+    c - b'x'
+}
+
+#[test]
+fn match_matches_array_version() {
+    (-3..=5).for_each(|a| {
+        (-3..=3).for_each(|b| assert_eq!(mix_match(a, b), mix_array(a, b)))
+    });
+}
+
+#[test]
+fn match_supports_modes() {
+    assert_eq!(axis(b'x'), Some(0));
+    assert_eq!(axis(b'z'), Some(2));
+    assert_eq!(axis(b'a'), None);
+    assert_eq!(axis_panic(b'y'), 1);
+}
+
+#[test]
+#[should_panic(expected = "argument `c` = 119 is out of the precalculated range")]
+fn match_panics_out_of_range() {
+    axis_panic(b'w');
+}
@@ -0,0 +1,31 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = 0..=4, name = add_small, export)]
+#[precalculate(a = 0..=100, b = 0..=40, name = add_big, option, export)]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[precalculate(x = 0..=3, name = square_small, value_stats)]
+#[inline]
+#[precalculate(x = 0..=15, name = square_big, value_stats)]
+/// Squares `x`.
+const fn square(x: u16) -> u16 {
+    x * x
+}
+
+#[test]
+fn stacked_attributes_generate_two_tables() {
+    assert_eq!(add_small_table().len(), 11);
+    assert_eq!(add_big_table().len(), 101);
+    assert_eq!(add_small(3, 4), 7);
+    assert_eq!(add_big(30, 40), Some(70));
+    assert_eq!(add_big(101, 0), None);
+}
+
+#[test]
+fn stacked_attributes_keep_other_attributes() {
+    assert_eq!(SQUARE_SMALL_VALUE_MAX, 9);
+    assert_eq!(SQUARE_BIG_VALUE_MAX, 225);
+    assert_eq!(square_small(2), square_big(2));
+}
@@ -0,0 +1,34 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, export)]
+const fn square(a: u32) -> u32 {
+    a * a
+}
+
+#[precalculate(a = start_count(0, 11), export)]
+const fn square_counted(a: u32) -> u32 {
+    a * a
+}
+
+const BUFFER_START: i16 = -8;
+const BUFFER_LEN: i16 = 16;
+
+#[precalculate(a = start_count(BUFFER_START, BUFFER_LEN), b = start_count(-2, 3), option)]
+const fn sum(a: i16, b: i16) -> i16 {
+    a + b
+}
+
+#[test]
+fn start_count_matches_inclusive_range() {
+    assert_eq!(square_counted_table(), square_table());
+    assert_eq!(SQUARE_COUNTED_DIMS, &[11]);
+}
+
+#[test]
+fn start_count_with_constants_and_negative_start() {
+    assert_eq!(SUM_DIMS, &[16, 3]);
+    assert_eq!(sum(-8, -2), Some(-10));
+    assert_eq!(sum(7, 0), Some(7));
+    assert_eq!(sum(8, 0), None);
+    assert_eq!(sum(0, 1), None);
+}
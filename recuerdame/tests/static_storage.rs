@@ -0,0 +1,32 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = 0..=4, storage = static, export)]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[cfg(target_os = "linux")]
+#[precalculate(a = 0..=255, storage = static, link_section = ".lookup", option)]
+const fn square(a: u8) -> u16 {
+    a as u16 * a as u16
+}
+
+#[test]
+fn static_table_lookups_work() {
+    assert_eq!(add(5, 2), 7);
+    assert_eq!(add(20, 0), 20);
+    assert!(std::ptr::eq(add_table(), add_table()));
+}
+
+#[test]
+fn static_table_is_usable_in_const_context() {
+    const SEVEN: i32 = add(5, 2);
+    assert_eq!(SEVEN, 7);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn static_table_in_link_section() {
+    assert_eq!(square(16), Some(256));
+    assert_eq!(square(255), Some(65025));
+}
@@ -0,0 +1,91 @@
+use recuerdame::precalculate;
+
+#[precalculate(x = 0..=100 by 10, export)]
+const fn square(x: u32) -> u32 {
+    x * x
+}
+
+#[precalculate(a = -20..=20 by 4, b = 0..=2, option)]
+const fn scaled(a: i32, b: i32) -> i32 {
+    a * b
+}
+
+#[test]
+fn stepped_table_holds_every_step() {
+    assert_eq!(square_table().len(), 11);
+    assert_eq!(square(0), 0);
+    assert_eq!(square(30), 900);
+    assert_eq!(square(100), 10_000);
+}
+
+#[test]
+fn between_steps_uses_value_below() {
+    assert_eq!(square(34), 900);
+    assert_eq!(square(39), 900);
+    assert_eq!(scaled(-17, 2), Some(-40));
+}
+
+#[test]
+fn nearest_rounds_to_closest_step() {
+    assert_eq!(square_nearest(34), 900);
+    assert_eq!(square_nearest(36), 1600);
+    assert_eq!(scaled_nearest(-15, 2), Some(-32));
+    assert_eq!(scaled_nearest(-13, 1), Some(-12));
+}
+
+#[test]
+fn nearest_rounds_halfway_up() {
+    assert_eq!(square_nearest(35), 1600);
+    assert_eq!(square_nearest(95), 10_000);
+    assert_eq!(scaled_nearest(-18, 1), Some(-16));
+    assert_eq!(scaled_nearest(2, 1), Some(4));
+}
+
+#[test]
+fn out_of_range_ignores_steps() {
+    assert_eq!(square(101), 101 * 101);
+    assert_eq!(scaled(21, 0), None);
+    assert_eq!(scaled_nearest(-21, 0), None);
+}
+
+#[precalculate(x = 100..=0 by -10, export)]
+const fn half(x: u32) -> u32 {
+    x / 2
+}
+
+#[precalculate(t = 3..=-9 by -3, option)]
+const fn negate(t: i8) -> i8 {
+    -t
+}
+
+#[test]
+fn descending_steps_are_stored_from_the_start() {
+    assert_eq!(half_table(), &[50, 45, 40, 35, 30, 25, 20, 15, 10, 5, 0]);
+    assert_eq!(half_checked(100), Some((0,)));
+    assert_eq!(half_checked(0), Some((10,)));
+}
+
+#[test]
+fn descending_lookups() {
+    assert_eq!(half(60), 30);
+    assert_eq!(negate(-6), Some(6));
+    assert_eq!(negate(4), None);
+    assert_eq!(negate(-10), None);
+}
+
+#[test]
+fn descending_between_steps_uses_value_closer_to_start() {
+    assert_eq!(half(95), 50);
+    assert_eq!(half(91), 50);
+    assert_eq!(negate(-7), Some(6));
+}
+
+#[test]
+fn descending_nearest_rounds_halfway_up() {
+    assert_eq!(half_nearest(95), 50);
+    assert_eq!(half_nearest(94), 45);
+    assert_eq!(half_nearest(96), 50);
+    assert_eq!(negate_nearest(-7), Some(6));
+    assert_eq!(negate_nearest(-8), Some(9));
+    assert_eq!(negate_nearest(2), Some(-3));
+}
@@ -0,0 +1,55 @@
+use recuerdame::{PrecalcConst, precalculate};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Stats {
+    sq: u16,
+    cube: u32,
+    even: bool,
+}
+
+impl PrecalcConst for Stats {
+    const DEFAULT: Self = Stats {
+        sq: 0,
+        cube: 0,
+        even: false,
+    };
+}
+
+#[precalculate(n = 0..=255)]
+const fn stats(n: u8) -> Stats {
+    let n = n as u32;
+    Stats {
+        sq: (n * n) as u16,
+        cube: n * n * n,
+        even: n.is_multiple_of(2),
+    }
+}
+
+#[test]
+fn every_field_matches_original() {
+    (0..=255).for_each(|n| {
+        let expected = _mod_precalc_stats::_stats_original(n);
+        let actual = stats(n);
+        assert_eq!(actual.sq, expected.sq);
+        assert_eq!(actual.cube, expected.cube);
+        assert_eq!(actual.even, expected.even);
+    });
+    assert_eq!(
+        stats(12),
+        Stats {
+            sq: 144,
+            cube: 1728,
+            even: true
+        }
+    );
+}
+
+#[test]
+fn struct_is_stored_in_a_single_table() {
+    let table = _mod_precalc_stats::LOOKUP_TABLE_STATS;
+    assert_eq!(table.len(), 256);
+    assert_eq!(
+        std::mem::size_of_val(table),
+        256 * std::mem::size_of::<Stats>()
+    );
+}
@@ -0,0 +1,29 @@
+use recuerdame::precalculate;
+use std::panic::{self, Location};
+use std::sync::Mutex;
+
+// Kept in its own test binary: the panic hook is process wide.
+
+#[precalculate(a = 0..=10, b = -4..=4, panic)]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[test]
+fn panic_location_is_the_caller() {
+    static LOCATION: Mutex<Option<(String, u32)>> = Mutex::new(None);
+
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| {
+        let location = info.location().unwrap();
+        *LOCATION.lock().unwrap() = Some((location.file().to_string(), location.line()));
+    }));
+    let caller = Location::caller();
+    let result = panic::catch_unwind(|| add(20, 0));
+    panic::set_hook(hook);
+
+    assert!(result.is_err());
+    let (file, line) = LOCATION.lock().unwrap().take().unwrap();
+    assert_eq!(file, caller.file());
+    assert_eq!(line, caller.line() + 1);
+}
@@ -0,0 +1,56 @@
+use recuerdame::precalculate;
+
+/// Sign-magnitude byte: bit 7 is the sign, bits 0-6 the magnitude.
+const fn decode(raw: u8) -> i16 {
+    let magnitude = (raw & 0x7f) as i16;
+    if raw & 0x80 != 0 { -magnitude } else { magnitude }
+}
+
+/// Maps -127..=127 to 0..=254, negative zero shares the index of zero.
+const fn to_index(raw: u8) -> usize {
+    (decode(raw) + 127) as usize
+}
+
+const fn from_index(index: usize) -> u8 {
+    let value = index as i16 - 127;
+    if value < 0 {
+        0x80 | (-value) as u8
+    } else {
+        value as u8
+    }
+}
+
+#[precalculate(raw = 0..=254, transform(raw = (to_index, from_index)), export)]
+const fn cube(raw: u8) -> i32 {
+    let value = decode(raw) as i32;
+    value * value * value
+}
+
+#[precalculate(raw = 127..=137, transform(raw = (to_index, from_index)), option)]
+const fn small_positive(raw: u8) -> i16 {
+    decode(raw)
+}
+
+#[test]
+fn transform_indexes_every_encoding() {
+    for raw in 0..=u8::MAX {
+        let value = decode(raw) as i32;
+        assert_eq!(cube(raw), value * value * value);
+    }
+}
+
+#[test]
+fn transform_table_is_compact() {
+    assert_eq!(cube_table().len(), 255);
+    assert_eq!(cube_table()[0], -127 * 127 * 127);
+    assert_eq!(cube_table()[127], 0);
+    assert_eq!(cube_checked(0x80), cube_checked(0x00));
+}
+
+#[test]
+fn transform_range_is_a_range_of_indices() {
+    assert_eq!(small_positive(0x00), Some(0));
+    assert_eq!(small_positive(0x0a), Some(10));
+    assert_eq!(small_positive(0x0b), None);
+    assert_eq!(small_positive(0x81), None);
+}
@@ -0,0 +1,10 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, assume_in_range, panic)]
+const fn double(a: u8) -> u8 {
+    a * 2
+}
+
+fn main() {
+    double(1);
+}
@@ -0,0 +1,11 @@
+use recuerdame::precalculate;
+
+#[precalculate(n = 0..=3, fill = "")]
+const fn name<'a>(n: u8) -> &'a str {
+    match n {
+        0 => "zero",
+        _ => "many",
+    }
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = 0..=4, check_order = [b, c])]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+fn main() {}
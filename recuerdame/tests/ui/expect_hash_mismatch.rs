@@ -0,0 +1,10 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = 0..=4, expect_hash = "0x0123456789abcdef")]
+const fn add(a: u8, b: u8) -> u8 {
+    a + b
+}
+
+fn main() {
+    add(1, 2);
+}
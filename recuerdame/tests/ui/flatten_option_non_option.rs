@@ -0,0 +1,10 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, option, flatten_option)]
+const fn double(a: i32) -> i32 {
+    a * 2
+}
+
+fn main() {
+    double(1);
+}
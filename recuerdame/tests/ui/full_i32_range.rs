@@ -0,0 +1,8 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = i32::MIN..=i32::MAX)]
+const fn identity(a: i32) -> i32 {
+    a
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10)]
+const fn double<T: Copy>(a: u8) -> u8 {
+    a * 2
+}
+
+fn main() {
+    double::<u8>(1);
+}
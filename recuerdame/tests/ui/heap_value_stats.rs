@@ -0,0 +1,8 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, storage = heap, value_stats)]
+const fn double(a: i32) -> i32 {
+    a * 2
+}
+
+fn main() {}
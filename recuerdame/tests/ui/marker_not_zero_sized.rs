@@ -0,0 +1,13 @@
+use recuerdame::precalculate;
+
+#[derive(Clone, Copy)]
+struct Scale(u32);
+
+#[precalculate(scale = marker(Scale(2)), n = 0..=10)]
+const fn scaled(scale: Scale, n: u32) -> u32 {
+    scale.0 * n
+}
+
+fn main() {
+    scaled(Scale(2), 1);
+}
@@ -0,0 +1,8 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=255, max_bytes = 255)]
+const fn identity(a: u8) -> u8 {
+    a
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use recuerdame::precalculate;
+
+struct Scaler(u8);
+
+impl Scaler {
+    #[precalculate(n = 0..=10)]
+    const fn scale(&self, n: u8) -> u8 {
+        n * self.0
+    }
+}
+
+fn main() {}
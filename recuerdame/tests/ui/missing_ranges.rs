@@ -0,0 +1,10 @@
+use recuerdame::precalculate;
+
+#[precalculate(b = 0..=4)]
+const fn mix(a: u8, b: u8, c: u8, d: u8) -> u8 {
+    a + b + c + d
+}
+
+fn main() {
+    mix(1, 2, 3, 4);
+}
@@ -0,0 +1,10 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, variant(option))]
+const fn double(a: u8) -> u8 {
+    a * 2
+}
+
+fn main() {
+    double(1);
+}
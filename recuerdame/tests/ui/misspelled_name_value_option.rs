@@ -0,0 +1,16 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, storag = static)]
+const fn double(a: u8) -> u8 {
+    a * 2
+}
+
+#[precalculate(a = 0..=10, lable = "doubles")]
+const fn triple(a: u8) -> u8 {
+    a * 3
+}
+
+fn main() {
+    double(1);
+    triple(1);
+}
@@ -0,0 +1,10 @@
+use recuerdame::precalculate;
+
+#[precalculate(option)]
+const fn answer() -> u8 {
+    42
+}
+
+fn main() {
+    answer();
+}
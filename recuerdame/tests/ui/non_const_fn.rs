@@ -0,0 +1,8 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10)]
+fn double(a: i32) -> i32 {
+    a * 2
+}
+
+fn main() {}
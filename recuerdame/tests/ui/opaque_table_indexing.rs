@@ -0,0 +1,11 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = 0..=4, opaque_table)]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+fn main() {
+    let _ = add_table()[5][2];
+    let _ = add_table().0;
+}
@@ -0,0 +1,10 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = 0..=10, packed)]
+const fn less(a: u8, b: u8) -> bool {
+    a < b
+}
+
+fn main() {
+    less(1, 2);
+}
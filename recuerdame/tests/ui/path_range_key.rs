@@ -0,0 +1,10 @@
+use recuerdame::precalculate;
+
+#[precalculate(self::a = 0..=10)]
+const fn double(a: u8) -> u8 {
+    a * 2
+}
+
+fn main() {
+    double(1);
+}
@@ -0,0 +1,10 @@
+use recuerdame::precalculate;
+
+const RANGES: [(i32, i32); 1] = [(0, 10)];
+
+#[precalculate(ranges_const = RANGES, b = 0..=4)]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+fn main() {}
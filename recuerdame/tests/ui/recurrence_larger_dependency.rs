@@ -0,0 +1,10 @@
+use recuerdame::precalculate;
+
+#[precalculate(n = 0..=10, recurrence(depends_on = [n + 1]))]
+const fn up(n: u8) -> u8 {
+    if n >= 10 { n } else { up(n + 1) }
+}
+
+fn main() {
+    up(1);
+}
@@ -0,0 +1,8 @@
+use recuerdame::precalculate;
+
+#[precalculate(x = 0..=4_000_000_000, small = match)]
+const fn halve(x: u32) -> u32 {
+    x / 2
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use recuerdame::precalculate;
+
+const MAX: i32 = 3;
+
+#[precalculate(a = 0..=MAX, small = match)]
+const fn double(a: i32) -> i32 {
+    a * 2
+}
+
+fn main() {}
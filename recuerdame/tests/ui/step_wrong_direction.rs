@@ -0,0 +1,10 @@
+use recuerdame::precalculate;
+
+#[precalculate(x = 0..=100 by -10)]
+const fn square(x: i32) -> i32 {
+    x * x
+}
+
+fn main() {
+    square(10);
+}
@@ -0,0 +1,10 @@
+use recuerdame::precalculate;
+
+#[precalculate(x = 0..=10, store = f16)]
+const fn half(x: u8) -> f64 {
+    x as f64 / 2.0
+}
+
+fn main() {
+    half(1);
+}
@@ -0,0 +1,10 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=(1 << 60) - 1, max_bytes = usize::MAX)]
+const fn widen(a: u64) -> u64 {
+    a
+}
+
+fn main() {
+    widen(1);
+}
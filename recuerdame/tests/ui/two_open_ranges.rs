@@ -0,0 +1,10 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0.., b = 0.., max_bytes = 64)]
+const fn add(a: u8, b: u8) -> u8 {
+    a + b
+}
+
+fn main() {
+    add(1, 2);
+}
@@ -0,0 +1,10 @@
+use recuerdame::precalculate;
+
+#[precalculate(x = 0..=5, values = &[0, 1, 4])]
+const fn square(x: u8) -> u16 {
+    x as u16 * x as u16
+}
+
+fn main() {
+    square(1);
+}
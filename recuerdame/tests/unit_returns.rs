@@ -0,0 +1,33 @@
+use recuerdame::{PrecalcConst, precalculate, precalculate_closure};
+
+#[precalculate(hour = 0..=23, minute = 0..=59, option)]
+const fn valid_time(hour: u8, minute: u8) {
+    let _ = (hour, minute);
+}
+
+#[precalculate(level = 1..=5, panic)]
+const fn check_level(level: u8) -> () {
+    let _ = level;
+}
+
+precalculate_closure!(in_byte_range, |x: i32| { let _ = x; }, x = 0..=255, option);
+
+#[test]
+fn unit_default() {
+    let () = <() as PrecalcConst>::DEFAULT;
+}
+
+#[test]
+fn unit_return_checks_range() {
+    assert_eq!(valid_time(23, 59), Some(()));
+    assert_eq!(valid_time(24, 0), None);
+    assert_eq!(in_byte_range(255), Some(()));
+    assert_eq!(in_byte_range(-1), None);
+    check_level(3);
+}
+
+#[test]
+#[should_panic(expected = "argument `level` = 6 is out of the precalculated range")]
+fn unit_return_panics_out_of_range() {
+    check_level(6);
+}
@@ -0,0 +1,66 @@
+use quickcheck_macros::quickcheck;
+use recuerdame::precalculate;
+
+#[precalculate(a = 10..=20)]
+const fn fallback(a: u8) -> u8 {
+    a.wrapping_mul(3)
+}
+
+#[precalculate(a = 10..=20, option)]
+const fn option(a: u8) -> u8 {
+    a.wrapping_mul(3)
+}
+
+#[precalculate(a = 10..=20, panic)]
+const fn panic(a: u8) -> u8 {
+    a.wrapping_mul(3)
+}
+
+#[precalculate(a = 1000..=2000 by 100, b = u64::MAX - 4..=u64::MAX, option)]
+const fn stepped(a: u16, b: u64) -> u64 {
+    a as u64 ^ b
+}
+
+#[precalculate(a = 2000..=1000 by -100, option)]
+const fn descending(a: u16) -> u16 {
+    a / 100
+}
+
+#[test]
+fn unsigned_inputs_near_min() {
+    for a in 0..=u8::MAX {
+        let in_range = (10..=20).contains(&a);
+        assert_eq!(fallback(a), a.wrapping_mul(3));
+        assert_eq!(option(a), in_range.then(|| a.wrapping_mul(3)));
+        assert_eq!(fallback_checked(a), in_range.then(|| ((a - 10) as usize,)));
+        if in_range {
+            assert_eq!(panic(a), a.wrapping_mul(3));
+        }
+    }
+}
+
+#[test]
+fn unsigned_panic_below_min() {
+    for a in 0..10 {
+        assert!(std::panic::catch_unwind(|| panic(a)).is_err());
+    }
+}
+
+#[test]
+fn unsigned_stepped_near_min() {
+    for a in 990..=1010 {
+        let expected = (1000..=2000).contains(&a).then(|| (a - a % 100) as u64 ^ (u64::MAX - 4));
+        assert_eq!(stepped(a, u64::MAX - 4), expected);
+    }
+    assert_eq!(stepped(1000, u64::MAX - 5), None);
+    assert_eq!(descending(999), None);
+    assert_eq!(descending(1000), Some(10));
+    // Between two steps, the value closer to the start of the range is used.
+    assert_eq!(descending(1050), Some(11));
+    assert_eq!(descending(2001), None);
+}
+
+#[quickcheck]
+fn unsigned_option_matches_original(a: u8) -> bool {
+    option(a) == (10..=20).contains(&a).then(|| a.wrapping_mul(3))
+}
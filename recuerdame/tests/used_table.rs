@@ -0,0 +1,48 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = -4..=4)]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[precalculate(x = clamp(0..=100), option)]
+const fn percent(x: u8) -> u8 {
+    x
+}
+
+#[precalculate(x = 0..=100 by 10, panic)]
+const fn tens(x: u32) -> u32 {
+    x / 10
+}
+
+#[test]
+fn used_table_matches_the_fallback() {
+    assert!(add_used_table(0, -4));
+    assert!(add_used_table(10, 4));
+    assert!(!add_used_table(11, 0));
+    assert!(!add_used_table(0, 5));
+    for a in -5..=15 {
+        for b in -8..=8 {
+            assert_eq!(add_used_table(a, b), add_checked(a, b).is_some());
+        }
+    }
+}
+
+#[test]
+fn clamped_inputs_always_use_the_table() {
+    assert!(percent_used_table(200));
+    assert_eq!(percent(200), Some(100));
+}
+
+#[test]
+fn used_table_follows_other_modes() {
+    assert!(tens_used_table(30));
+    assert!(!tens_used_table(110));
+}
+
+const ADD_IN_TABLE: bool = add_used_table(3, 2);
+
+#[test]
+fn used_table_is_const() {
+    const { assert!(ADD_IN_TABLE) };
+}
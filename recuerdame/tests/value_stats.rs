@@ -0,0 +1,25 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = 0..=4, value_stats)]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[precalculate(x = -8..=3, storage = static, value_stats)]
+const fn square(x: i8) -> u8 {
+    (x as i16 * x as i16) as u8
+}
+
+#[test]
+fn value_stats_of_add() {
+    assert_eq!(ADD_VALUE_MIN, 0);
+    assert_eq!(ADD_VALUE_MAX, 14);
+}
+
+#[test]
+fn value_stats_of_static_table() {
+    assert_eq!(SQUARE_VALUE_MIN, 0);
+    assert_eq!(SQUARE_VALUE_MAX, 64);
+}
+
+const _: () = assert!(SQUARE_VALUE_MAX <= i8::MAX as u8);
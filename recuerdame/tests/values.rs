@@ -0,0 +1,36 @@
+use recuerdame::precalculate;
+
+const SQUARES: [u16; 6] = [0, 1, 4, 9, 16, 25];
+
+#[precalculate(x = 0..=5, values = &SQUARES)]
+const fn square(x: u8) -> u16 {
+    x as u16 * x as u16
+}
+
+// Row-major: `b` varies fastest.
+const PRODUCTS: &[i32] = &[
+    -2, 0, 2, //
+    -4, 0, 4, //
+    -6, 0, 6,
+];
+
+#[precalculate(a = 1..=3, b = -1..=1, values = PRODUCTS, option, export)]
+const fn mul(a: i32, b: i32) -> i32 {
+    a * b * 2
+}
+
+#[test]
+fn values_populate_the_table() {
+    for x in 0..=5 {
+        assert_eq!(square(x), SQUARES[x as usize]);
+    }
+    assert_eq!(square(9), 81);
+}
+
+#[test]
+fn values_are_row_major() {
+    assert_eq!(mul_table(), &[[-2, 0, 2], [-4, 0, 4], [-6, 0, 6]]);
+    assert_eq!(mul(3, 1), Some(6));
+    assert_eq!(mul(2, -1), Some(-4));
+    assert_eq!(mul(4, 0), None);
+}
@@ -0,0 +1,86 @@
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = 0..=4, panic, variants(option, fallback))]
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[precalculate(a = 0..=10, variants(panic))]
+const fn double(a: i32) -> i32 {
+    a * 2
+}
+
+#[precalculate(a = 0..=10, b = 0..=4, variants(option, keep))]
+const fn sub(a: i32, b: i32) -> i32 {
+    a - b
+}
+
+const SHIFTED: &[u8] = &[10, 11, 12, 13];
+
+// The table differs from the function, so reading it shows in the results.
+#[precalculate(x = 0..=3, values = SHIFTED, variants(option, panic, fallback))]
+const fn identity(x: u8) -> u8 {
+    x
+}
+
+#[test]
+fn all_variants_agree_in_range() {
+    (0..=10).for_each(|a| {
+        (0..=4).for_each(|b| {
+            let expected = _mod_precalc_add::_add_original(a, b);
+            assert_eq!(add(a, b), expected);
+            assert_eq!(add_opt(a, b), Some(expected));
+            assert_eq!(add_fallback(a, b), expected);
+        })
+    });
+}
+
+#[test]
+fn option_variant_is_none_out_of_range() {
+    assert_eq!(add_opt(11, 0), None);
+    assert_eq!(add_opt(0, -1), None);
+}
+
+#[test]
+fn fallback_variant_uses_original_out_of_range() {
+    assert_eq!(add_fallback(20, 0), 20);
+    assert_eq!(add_fallback(-3, 9), 6);
+}
+
+#[test]
+#[should_panic]
+fn main_function_keeps_its_mode() {
+    add(11, 0);
+}
+
+#[test]
+fn variants_combine_with_default_mode() {
+    assert_eq!(double(20), 40);
+    assert_eq!(double_panic(5), 10);
+}
+
+#[test]
+fn basic_option_and_keep_variants() {
+    for a in 0..=10 {
+        for b in 0..=4 {
+            assert_eq!(sub(a, b), a - b);
+            assert_eq!(sub_opt(a, b), Some(a - b));
+            assert_eq!(sub_keep(a, b), a - b);
+        }
+    }
+    assert_eq!(sub(20, 3), 17);
+    assert_eq!(sub_opt(20, 3), None);
+    assert_eq!(sub_keep(20, 3), 17);
+}
+
+#[test]
+fn variants_read_the_same_table() {
+    for x in 0..=3 {
+        let stored = SHIFTED[x as usize];
+        assert_eq!(identity(x), stored);
+        assert_eq!(identity_opt(x), Some(stored));
+        assert_eq!(identity_panic(x), stored);
+        assert_eq!(identity_fallback(x), stored);
+    }
+    assert_eq!(identity_fallback(4), 4);
+}
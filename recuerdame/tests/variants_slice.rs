@@ -0,0 +1,75 @@
+use recuerdame::precalculate;
+
+// Discriminants are neither contiguous nor listed in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Opcode {
+    Nop = 0,
+    Load = 4,
+    Store = 5,
+    Jump = 9,
+    Halt = 2,
+}
+
+impl Opcode {
+    const VARIANTS: &[Self] = &[
+        Opcode::Load,
+        Opcode::Store,
+        Opcode::Nop,
+        Opcode::Jump,
+        Opcode::Halt,
+    ];
+
+    const HALTLESS: &[Self] = &[Opcode::Nop, Opcode::Load, Opcode::Store, Opcode::Jump];
+}
+
+#[precalculate(op = Opcode::VARIANTS, n = 0..=3)]
+const fn cycles(op: Opcode, n: u8) -> u32 {
+    let base = match op {
+        Opcode::Nop => 1,
+        Opcode::Load | Opcode::Store => 3,
+        Opcode::Jump => 2,
+        Opcode::Halt => 0,
+    };
+    base * (n as u32 + 1)
+}
+
+mod subset {
+    use super::Opcode;
+    use recuerdame::precalculate;
+
+    // Any path ending in `VARIANTS` works, so a subset can be listed too.
+    pub mod ops {
+        pub const VARIANTS: &[super::Opcode] = super::Opcode::HALTLESS;
+    }
+
+    #[precalculate(op = ops::VARIANTS, option)]
+    pub const fn is_memory(op: Opcode) -> bool {
+        matches!(op, Opcode::Load | Opcode::Store)
+    }
+}
+
+#[test]
+fn table_over_every_listed_variant() {
+    assert_eq!(CYCLES_DIMS, [5, 4]);
+    for &op in Opcode::VARIANTS {
+        for n in 0..=3 {
+            assert_eq!(cycles(op, n), _mod_precalc_cycles::_cycles_original(op, n));
+        }
+    }
+    assert_eq!(cycles(Opcode::Jump, 3), 8);
+}
+
+#[test]
+fn indexed_by_position_in_the_slice() {
+    assert_eq!(cycles_checked(Opcode::Load, 0), Some((0, 0)));
+    assert_eq!(cycles_checked(Opcode::Nop, 1), Some((2, 1)));
+    assert_eq!(cycles_checked(Opcode::Halt, 2), Some((4, 2)));
+    assert_eq!(cycles_unindex(13), (Opcode::Jump, 1));
+}
+
+#[test]
+fn variants_missing_from_the_slice_are_out_of_range() {
+    assert_eq!(subset::is_memory(Opcode::Store), Some(true));
+    assert_eq!(subset::is_memory(Opcode::Jump), Some(false));
+    assert_eq!(subset::is_memory(Opcode::Halt), None);
+}
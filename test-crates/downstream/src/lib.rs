@@ -0,0 +1,19 @@
+//! Downstream crate reusing the table precalculated by `upstream`.
+
+use recuerdame_test_upstream::add_table;
+
+/// Sums every entry of the upstream table without regenerating it.
+pub const fn sum_of_table() -> i32 {
+    let table = add_table();
+    let mut sum = 0;
+    let mut a = 0;
+    while a < table.len() {
+        let mut b = 0;
+        while b < table[a].len() {
+            sum += table[a][b];
+            b += 1;
+        }
+        a += 1;
+    }
+    sum
+}
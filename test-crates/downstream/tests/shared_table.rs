@@ -0,0 +1,17 @@
+use recuerdame_test_downstream::sum_of_table;
+use recuerdame_test_upstream::{add, add_table};
+
+#[test]
+fn exported_table_matches_upstream_function() {
+    let table = add_table();
+    assert_eq!(table.len(), 11);
+    (0..=10).for_each(|a| {
+        (0..=4).for_each(|b| assert_eq!(table[a as usize][b as usize], add(a, b)))
+    });
+}
+
+#[test]
+fn exported_table_is_usable_in_const_context() {
+    const SUM: i32 = sum_of_table();
+    assert_eq!(SUM, (0..=10).flat_map(|a| (0..=4).map(move |b| a + b)).sum());
+}
@@ -0,0 +1,12 @@
+//! Upstream crate exporting a precalculated table for `downstream` to reuse.
+//! It also opts into pedantic lints, so the workspace clippy run checks that
+//! generated code stays quiet.
+
+#![warn(clippy::pedantic)]
+
+use recuerdame::precalculate;
+
+#[precalculate(a = 0..=10, b = 0..=4, export)]
+pub const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}